@@ -0,0 +1,4 @@
+pub mod context;
+pub mod input;
+pub mod screen;
+pub mod screen_stack;
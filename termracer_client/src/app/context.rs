@@ -0,0 +1,25 @@
+use crossterm::terminal;
+
+use crate::framework::window::Window;
+use crate::models::game_result::GameResult;
+use crate::models::game_settings::GameSettings;
+
+// state shared by every screen
+pub struct Context {
+  pub window: Window,
+  // settings the next test is started with
+  pub settings: GameSettings,
+  pub last_result: Option<GameResult>,
+}
+
+impl Context {
+  // creates a window the size of the terminal
+  pub fn new(settings: GameSettings) -> Self {
+    let (term_width, term_height) = terminal::size().expect("ERROR: Failed to get terminal size.");
+    Context {
+      window: Window::new(term_width, term_height),
+      settings,
+      last_result: None,
+    }
+  }
+}
@@ -0,0 +1,74 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event};
+
+// how often the input thread checks whether it should stop
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+// an event along with when it was read, which is as close to when the key
+// was pressed as the terminal allows
+pub struct TimedEvent {
+  pub event: Event,
+  pub time: Instant,
+}
+
+// reads events on a thread of its own so that they're timestamped as soon as
+// they come in, however busy the game loop is
+pub struct Input {
+  receiver: Receiver<TimedEvent>,
+  stop: Arc<AtomicBool>,
+  handle: Option<JoinHandle<()>>,
+}
+
+impl Input {
+  pub fn spawn() -> Self {
+    let (sender, receiver) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+
+    let thread_stop = stop.clone();
+    let handle = thread::spawn(move || {
+      while !thread_stop.load(Ordering::Relaxed) {
+        if !event::poll(STOP_CHECK_INTERVAL).expect("ERROR: Failed to poll event.") {
+          continue;
+        }
+        let event = event::read().expect("ERROR: Failed to read event.");
+        let time = Instant::now();
+        if sender.send(TimedEvent { event, time }).is_err() {
+          break;
+        }
+      }
+    });
+
+    Input {
+      receiver,
+      stop,
+      handle: Some(handle),
+    }
+  }
+
+  // waits up to a timeout for the next event
+  pub fn next_event(&self, timeout: Duration) -> Option<TimedEvent> {
+    match self.receiver.recv_timeout(timeout) {
+      Ok(event) => Some(event),
+      Err(RecvTimeoutError::Timeout) => None,
+      Err(RecvTimeoutError::Disconnected) => panic!("ERROR: Input thread stopped."),
+    }
+  }
+}
+
+impl Drop for Input {
+  // the thread is stopped before the terminal is restored, so that it doesn't
+  // read anything typed afterwards
+  fn drop(&mut self) {
+    self.stop.store(true, Ordering::Relaxed);
+    if let Some(handle) = self.handle.take() {
+      if handle.join().is_err() && !thread::panicking() {
+        panic!("ERROR: Input thread panicked.");
+      }
+    }
+  }
+}
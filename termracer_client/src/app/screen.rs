@@ -0,0 +1,90 @@
+use std::time::Instant;
+
+use crossterm::event::{Event, KeyEvent};
+
+use super::context::Context;
+use crate::views::view::KeyEventHandleable;
+
+// creates a screen once the window has a fresh layout for it to split up
+pub type ScreenFactory = Box<dyn FnOnce(&mut Context) -> Box<dyn Screen>>;
+
+pub enum Transition {
+  Stay,
+  // shows a new screen on top of the current one
+  Push(ScreenFactory),
+  // goes back to the screen underneath, quitting if there isn't one
+  Pop,
+  // swaps the current screen for a new one
+  Replace(ScreenFactory),
+  Quit,
+}
+
+impl Transition {
+  pub fn push<S, F>(create: F) -> Self
+  where
+    S: Screen + 'static,
+    F: FnOnce(&mut Context) -> S + 'static,
+  {
+    Transition::Push(Box::new(|ctx| Box::new(create(ctx))))
+  }
+
+  pub fn replace<S, F>(create: F) -> Self
+  where
+    S: Screen + 'static,
+    F: FnOnce(&mut Context) -> S + 'static,
+  {
+    Transition::Replace(Box::new(|ctx| Box::new(create(ctx))))
+  }
+}
+
+// a page of the app, like the menu or a test, which only gets events while
+// it's on top of the stack
+pub trait Screen {
+  // smallest window the screen can be drawn in, as width and height
+  fn min_size(&self) -> (u16, u16) {
+    (1, 1)
+  }
+
+  // handles a key event before it reaches the focused view, returning None to
+  // pass it on -- time is when the key was pressed
+  fn handle_key_event(
+    &mut self,
+    _ctx: &mut Context,
+    _event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    None
+  }
+
+  // the view that key events go to when the screen doesn't handle them
+  fn focused_view(&mut self) -> Option<&mut dyn KeyEventHandleable> {
+    None
+  }
+
+  // handles events other than key presses and resizes, like focus changes
+  fn handle_event(&mut self, _ctx: &mut Context, _event: &Event) -> Transition {
+    Transition::Stay
+  }
+
+  // called after the window is resized while the screen is on top
+  fn on_resize(&mut self, _ctx: &mut Context) {}
+
+  // called when the screen is back on top after the one above it is popped,
+  // in case anything changed in the meantime
+  fn on_resume(&mut self, ctx: &mut Context) {
+    self.on_resize(ctx);
+  }
+
+  // called when the window gets too small for the screen, which is hidden
+  // until the window is big enough again
+  fn on_hidden(&mut self, _ctx: &mut Context) {}
+
+  fn on_shown(&mut self, _ctx: &mut Context) {}
+
+  // called once per frame, before the screen is drawn
+  fn update(&mut self, _ctx: &mut Context) -> Transition {
+    Transition::Stay
+  }
+
+  fn draw(&mut self, ctx: &mut Context);
+}
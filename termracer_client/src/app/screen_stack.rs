@@ -0,0 +1,360 @@
+use std::io::Write;
+use std::time::Instant;
+
+use crossterm::event::{DisableFocusChange, EnableFocusChange, Event, KeyCode, KeyModifiers};
+use crossterm::{execute, terminal};
+
+use super::context::Context;
+use super::input::{Input, TimedEvent};
+use super::screen::{Screen, Transition};
+use crate::framework::frame_scheduler::{FrameScheduler, FrameTiming};
+use crate::views::overlay::Overlay;
+use crate::views::view::View;
+
+const TOO_SMALL_MESSAGE: &str = "Terminal too small";
+
+// runs screens on top of each other in one terminal session, with the top
+// screen getting events and drawing to the window
+pub struct ScreenStack {
+  context: Context,
+  screens: Vec<Box<dyn Screen>>,
+  // whether the top screen is hidden because the window is too small for it
+  hidden: bool,
+}
+
+impl ScreenStack {
+  pub fn new(context: Context) -> Self {
+    ScreenStack {
+      context,
+      screens: vec![],
+      hidden: false,
+    }
+  }
+
+  // what the screens left behind, like the result of the last test
+  pub fn into_context(self) -> Context {
+    self.context
+  }
+
+  // shows the first screen and runs until the last screen is popped, drawing
+  // at a target number of frames per second
+  pub fn run<T, S, F>(&mut self, buf: &mut T, fps: u32, create_first: F) -> FrameTiming
+  where
+    T: Write,
+    S: Screen + 'static,
+    F: FnOnce(&mut Context) -> S + 'static,
+  {
+    execute!(buf, terminal::EnterAlternateScreen, EnableFocusChange)
+      .expect("ERROR: Failed to enter alternate screen.");
+    terminal::enable_raw_mode().expect("ERROR: Failed to enable raw mode.");
+
+    let input = Input::spawn();
+    let mut scheduler = FrameScheduler::new(fps, Instant::now());
+    self.apply(Transition::push(create_first));
+    self.run_loop(buf, &input, &mut scheduler);
+    drop(input);
+
+    terminal::disable_raw_mode().expect("ERROR: Failed to disable raw mode.");
+    execute!(buf, DisableFocusChange, terminal::LeaveAlternateScreen)
+      .expect("ERROR: Failed to leave alternate screen.");
+    scheduler.into_timing()
+  }
+
+  // events are handled as they come in, and frames are drawn when they're due
+  // whether or not events keep coming
+  fn run_loop<T: Write>(&mut self, buf: &mut T, input: &Input, scheduler: &mut FrameScheduler) {
+    while !self.screens.is_empty() {
+      let timeout = scheduler.time_until_frame(Instant::now());
+      if let Some(event) = input.next_event(timeout) {
+        if let Event::Key(_) = event.event {
+          scheduler.input_received(event.time);
+        }
+        let transition = self.handle_event(event);
+        self.apply(transition);
+      }
+
+      let started = Instant::now();
+      if !self.screens.is_empty() && scheduler.is_frame_due(started) {
+        let transition = self.draw_frame(buf);
+        self.apply(transition);
+        scheduler.frame_done(started, Instant::now());
+      }
+    }
+  }
+
+  fn handle_event(&mut self, TimedEvent { event, time }: TimedEvent) -> Transition {
+    let Some(screen) = self.screens.last_mut() else {
+      return Transition::Stay;
+    };
+    let ctx = &mut self.context;
+    match event {
+      // a hidden screen doesn't get keys, but there's still a way out
+      Event::Key(key_event) if self.hidden => match (key_event.code, key_event.modifiers) {
+        (KeyCode::Char('c'), KeyModifiers::CONTROL) => Transition::Quit,
+        _ => Transition::Stay,
+      },
+      Event::Key(key_event) => match screen.handle_key_event(ctx, key_event, time) {
+        Some(transition) => transition,
+        None => {
+          if let Some(view) = screen.focused_view() {
+            view.handle_key_event(key_event);
+          }
+          Transition::Stay
+        }
+      },
+      Event::Resize(width, height) => {
+        ctx.window.resize(width, height);
+        ctx.window.clear();
+        if !self.hidden {
+          screen.on_resize(ctx);
+        }
+        self.check_size();
+        Transition::Stay
+      }
+      event => screen.handle_event(ctx, &event),
+    }
+  }
+
+  // updates and draws the top screen, or the message hiding it
+  fn draw_frame<T: Write>(&mut self, buf: &mut T) -> Transition {
+    let Some(screen) = self.screens.last_mut() else {
+      return Transition::Stay;
+    };
+    let ctx = &mut self.context;
+    let transition = if self.hidden {
+      let (min_width, min_height) = screen.min_size();
+      let message = format!("{} (need {}x{})", TOO_SMALL_MESSAGE, min_width, min_height);
+      Overlay::new(0, &message).draw(&mut ctx.window);
+      ctx.window.set_cursor_visible(false);
+      Transition::Stay
+    } else {
+      let transition = screen.update(ctx);
+      if let Transition::Stay = transition {
+        screen.draw(ctx);
+      }
+      transition
+    };
+    if let Transition::Stay = transition {
+      ctx.window.display(buf);
+      buf.flush().expect("ERROR: Failed to flush buffer.");
+    }
+    transition
+  }
+
+  fn apply(&mut self, transition: Transition) {
+    if let Transition::Stay = transition {
+      return;
+    }
+    // the screen being left is put back the way it was first
+    if self.hidden {
+      self.show();
+    }
+
+    match transition {
+      Transition::Stay => (),
+      Transition::Push(create) => {
+        self.context.window.push_layout();
+        let screen = create(&mut self.context);
+        self.screens.push(screen);
+      }
+      Transition::Pop => {
+        self.screens.pop();
+        self.context.window.pop_layout();
+        if let Some(screen) = self.screens.last_mut() {
+          screen.on_resume(&mut self.context);
+        }
+      }
+      Transition::Replace(create) => {
+        self.screens.pop();
+        self.context.window.pop_layout();
+        self.apply(Transition::Push(create));
+      }
+      Transition::Quit => {
+        while !self.screens.is_empty() {
+          self.screens.pop();
+          self.context.window.pop_layout();
+        }
+      }
+    }
+    self.check_size();
+  }
+
+  // hides the top screen behind a message while the window is smaller than it
+  // needs, and shows it again once the window is big enough
+  fn check_size(&mut self) {
+    let Some(screen) = self.screens.last_mut() else {
+      return;
+    };
+    let (width, height) = self.context.window.size();
+    let (min_width, min_height) = screen.min_size();
+    let fits = width >= min_width && height >= min_height;
+
+    if !fits && !self.hidden {
+      // the message gets a layout of its own so that the screen's layout is
+      // left alone
+      self.context.window.push_layout();
+      screen.on_hidden(&mut self.context);
+      self.hidden = true;
+    } else if fits && self.hidden {
+      self.show();
+    }
+  }
+
+  fn show(&mut self) {
+    self.context.window.pop_layout();
+    self.hidden = false;
+    if let Some(screen) = self.screens.last_mut() {
+      screen.on_shown(&mut self.context);
+      // the window might have been resized while the screen was hidden
+      screen.on_resize(&mut self.context);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::cell::RefCell;
+  use std::rc::Rc;
+
+  use super::*;
+  use crate::framework::split::HorizontalSplitKind;
+  use crate::framework::window::Window;
+  use crate::models::game_settings::GameSettings;
+
+  // records what happens to each screen by name
+  type Log = Rc<RefCell<Vec<String>>>;
+
+  struct TestScreen {
+    name: &'static str,
+    log: Log,
+    min_size: (u16, u16),
+  }
+
+  impl TestScreen {
+    fn new(ctx: &mut Context, name: &'static str, log: &Log) -> Self {
+      // each screen splits its own layout
+      ctx
+        .window
+        .horizontal_split(HorizontalSplitKind::CellsInTop(1), 0);
+      TestScreen {
+        name,
+        log: log.clone(),
+        min_size: (1, 1),
+      }
+    }
+  }
+
+  impl Screen for TestScreen {
+    fn min_size(&self) -> (u16, u16) {
+      self.min_size
+    }
+
+    fn on_hidden(&mut self, _ctx: &mut Context) {
+      self.log.borrow_mut().push(format!("hide {}", self.name));
+    }
+
+    fn on_shown(&mut self, _ctx: &mut Context) {
+      self.log.borrow_mut().push(format!("show {}", self.name));
+    }
+
+    fn on_resume(&mut self, _ctx: &mut Context) {
+      self.log.borrow_mut().push(format!("resume {}", self.name));
+    }
+
+    fn draw(&mut self, _ctx: &mut Context) {}
+  }
+
+  fn push(name: &'static str, log: &Log) -> Transition {
+    let log = log.clone();
+    Transition::push(move |ctx| TestScreen::new(ctx, name, &log))
+  }
+
+  fn replace(name: &'static str, log: &Log) -> Transition {
+    let log = log.clone();
+    Transition::replace(move |ctx| TestScreen::new(ctx, name, &log))
+  }
+
+  fn create_stack() -> ScreenStack {
+    ScreenStack::new(Context {
+      window: Window::new(10, 4),
+      settings: GameSettings::default(),
+      last_result: None,
+    })
+  }
+
+  #[test]
+  fn it_pushes_and_pops_screens() {
+    let log: Log = Rc::default();
+    let mut stack = create_stack();
+
+    stack.apply(push("menu", &log));
+    stack.apply(push("settings", &log));
+    assert_eq!(stack.screens.len(), 2);
+    // the top screen's layout is the one being drawn to
+    assert!(stack.context.window.region(1).is_some());
+    assert!(stack.context.window.region(2).is_none());
+
+    stack.apply(Transition::Pop);
+    assert_eq!(stack.screens.len(), 1);
+    assert_eq!(*log.borrow(), vec!["resume menu"]);
+
+    stack.apply(Transition::Pop);
+    assert!(stack.screens.is_empty());
+  }
+
+  #[test]
+  fn it_replaces_screens() {
+    let log: Log = Rc::default();
+    let mut stack = create_stack();
+
+    stack.apply(push("menu", &log));
+    stack.apply(push("game", &log));
+    stack.apply(replace("results", &log));
+    assert_eq!(stack.screens.len(), 2);
+
+    stack.apply(Transition::Pop);
+    assert_eq!(*log.borrow(), vec!["resume menu"]);
+
+    stack.apply(push("game", &log));
+    stack.apply(Transition::Quit);
+    assert!(stack.screens.is_empty());
+  }
+
+  #[test]
+  fn it_hides_screens_that_dont_fit() {
+    let log: Log = Rc::default();
+    let mut stack = create_stack();
+
+    stack.apply(push("menu", &log));
+    let big_log = log.clone();
+    stack.apply(Transition::push(move |ctx| {
+      let mut screen = TestScreen::new(ctx, "game", &big_log);
+      screen.min_size = (20, 4);
+      screen
+    }));
+    assert!(stack.hidden);
+    // the screen's regions are kept out of the way of the message
+    assert!(stack.context.window.region(1).is_none());
+
+    stack.context.window.resize(20, 4);
+    stack.check_size();
+    assert!(!stack.hidden);
+    assert!(stack.context.window.region(1).is_some());
+
+    // leaving a hidden screen shows it first
+    stack.context.window.resize(10, 4);
+    stack.check_size();
+    stack.apply(Transition::Pop);
+    assert!(!stack.hidden);
+    assert_eq!(
+      *log.borrow(),
+      vec![
+        "hide game",
+        "show game",
+        "hide game",
+        "show game",
+        "resume menu"
+      ]
+    );
+  }
+}
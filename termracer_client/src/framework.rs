@@ -1,6 +1,10 @@
+pub mod border;
+pub mod constraint;
 pub mod coord;
+pub mod frame_scheduler;
+pub mod rect;
 pub mod split;
 pub mod window;
+pub mod wrap;
 
 mod layout;
-mod rect;
@@ -0,0 +1,154 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use crossterm::style::Color;
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+
+// box drawing characters making up a border
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BorderChars {
+  pub horizontal: &'static str,
+  pub vertical: &'static str,
+  pub top_left: &'static str,
+  pub top_right: &'static str,
+  pub bottom_left: &'static str,
+  pub bottom_right: &'static str,
+}
+
+pub const SINGLE: BorderChars = BorderChars {
+  horizontal: "─",
+  vertical: "│",
+  top_left: "┌",
+  top_right: "┐",
+  bottom_left: "└",
+  bottom_right: "┘",
+};
+
+pub const DOUBLE: BorderChars = BorderChars {
+  horizontal: "═",
+  vertical: "║",
+  top_left: "╔",
+  top_right: "╗",
+  bottom_left: "╚",
+  bottom_right: "╝",
+};
+
+pub const ROUNDED: BorderChars = BorderChars {
+  horizontal: "─",
+  vertical: "│",
+  top_left: "╭",
+  top_right: "╮",
+  bottom_left: "╰",
+  bottom_right: "╯",
+};
+
+// for terminals without box drawing characters
+pub const ASCII: BorderChars = BorderChars {
+  horizontal: "-",
+  vertical: "|",
+  top_left: "+",
+  top_right: "+",
+  bottom_left: "+",
+  bottom_right: "+",
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BorderStyle {
+  Single,
+  Double,
+  Rounded,
+  // for terminals without box drawing characters
+  Ascii,
+}
+
+impl BorderStyle {
+  pub fn chars(self) -> &'static BorderChars {
+    match self {
+      BorderStyle::Single => &SINGLE,
+      BorderStyle::Double => &DOUBLE,
+      BorderStyle::Rounded => &ROUNDED,
+      BorderStyle::Ascii => &ASCII,
+    }
+  }
+}
+
+impl fmt::Display for BorderStyle {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = self
+      .to_possible_value()
+      .expect("ERROR: Failed to name border style.");
+    write!(f, "{}", name.get_name())
+  }
+}
+
+// a border drawn by the window around a region, taking up a cell on each
+// side of it
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Border {
+  pub style: BorderStyle,
+  pub title: Option<String>,
+  pub fg: Color,
+}
+
+impl Border {
+  pub fn new(style: BorderStyle) -> Self {
+    Border {
+      style,
+      title: None,
+      fg: Color::White,
+    }
+  }
+}
+
+// the top edge of a border, with a title set into it if there's room
+pub fn top_edge(chars: &BorderChars, width: usize, title: Option<&str>) -> String {
+  edge(
+    chars.top_left,
+    chars.horizontal,
+    chars.top_right,
+    width,
+    title,
+  )
+}
+
+pub fn bottom_edge(chars: &BorderChars, width: usize) -> String {
+  edge(
+    chars.bottom_left,
+    chars.horizontal,
+    chars.bottom_right,
+    width,
+    None,
+  )
+}
+
+fn edge(left: &str, fill: &str, right: &str, width: usize, title: Option<&str>) -> String {
+  if width < 2 {
+    return fill.repeat(width);
+  }
+  let inner = width - 2;
+  let title = match title {
+    Some(title) if title.width() + 2 <= inner => format!(" {} ", title),
+    _ => String::new(),
+  };
+  format!(
+    "{}{}{}{}",
+    left,
+    title,
+    fill.repeat(inner - title.width()),
+    right
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_sets_titles_into_edges() {
+    assert_eq!(top_edge(&ROUNDED, 10, Some("Hi")), "╭ Hi ────╮");
+    assert_eq!(top_edge(&ASCII, 6, Some("Title")), "+----+");
+    assert_eq!(bottom_edge(&DOUBLE, 4), "╚══╝");
+  }
+}
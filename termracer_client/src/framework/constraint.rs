@@ -0,0 +1,182 @@
+use std::cmp;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+  // children side by side, from left to right
+  Horizontal,
+  // children stacked from top to bottom
+  Vertical,
+}
+
+// how big one child of a split should be along the direction of the split
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Constraint {
+  // exactly this many cells
+  Fixed(u16),
+  // at least this many cells, growing to share leftover space like Fill(1)
+  Min(u16),
+  // up to this many cells, taking leftover space before anything that fills
+  Max(u16),
+  // a fraction of the total, numerator over denominator
+  Ratio(u16, u16),
+  // a share of whatever is left over, in proportion to its weight
+  Fill(u16),
+}
+
+// sizes of the children of a split, which never add up to more than the
+// total -- when there isn't room for everything, ratios and fixed sizes
+// shrink before minimum sizes, later children before earlier ones, so that
+// minimum sizes hold for as long as possible instead of regions silently
+// ending up empty
+pub fn solve(constraints: &[Constraint], total: u16) -> Vec<u16> {
+  let mut sizes: Vec<u16> = constraints
+    .iter()
+    .map(|constraint| match *constraint {
+      Constraint::Fixed(n) | Constraint::Min(n) => n,
+      Constraint::Ratio(numerator, denominator) => {
+        assert!(denominator > 0, "ERROR: Ratio denominator cannot be 0.");
+        // a fraction over one can't take more than the whole
+        (total as u32 * numerator as u32 / denominator as u32).min(total as u32) as u16
+      }
+      Constraint::Max(_) | Constraint::Fill(_) => 0,
+    })
+    .collect();
+
+  let used: u32 = sizes.iter().map(|&size| size as u32).sum();
+  if used > total as u32 {
+    shrink(constraints, &mut sizes, (used - total as u32) as u16);
+    return sizes;
+  }
+  let mut left = total - used as u16;
+
+  // maximum sizes are filled first, evenly
+  let maxes: Vec<(usize, u16)> = constraints
+    .iter()
+    .enumerate()
+    .filter_map(|(i, constraint)| match *constraint {
+      Constraint::Max(n) => Some((i, n)),
+      _ => None,
+    })
+    .collect();
+  while left > 0 {
+    let growing: Vec<usize> = maxes
+      .iter()
+      .filter(|&&(i, n)| sizes[i] < n)
+      .map(|&(i, _)| i)
+      .collect();
+    if growing.is_empty() {
+      break;
+    }
+    let share = cmp::max(left / growing.len() as u16, 1);
+    for i in growing {
+      let Constraint::Max(n) = constraints[i] else {
+        unreachable!();
+      };
+      let grow = cmp::min(cmp::min(share, n - sizes[i]), left);
+      sizes[i] += grow;
+      left -= grow;
+    }
+  }
+
+  // then whatever is left is shared by weight
+  let weights: Vec<(usize, u16)> = constraints
+    .iter()
+    .enumerate()
+    .filter_map(|(i, constraint)| match *constraint {
+      Constraint::Fill(weight) => Some((i, weight)),
+      Constraint::Min(_) => Some((i, 1)),
+      _ => None,
+    })
+    .collect();
+  let total_weight: u32 = weights.iter().map(|&(_, weight)| weight as u32).sum();
+  let mut given = 0;
+  for &(i, weight) in weights.iter() {
+    let share = (left as u32 * weight as u32)
+      .checked_div(total_weight)
+      .unwrap_or(0) as u16;
+    sizes[i] += share;
+    given += share;
+  }
+  // cells lost to rounding go to the first children with any weight
+  for &(i, _) in weights.iter().filter(|&&(_, weight)| weight > 0) {
+    if given == left {
+      break;
+    }
+    sizes[i] += 1;
+    given += 1;
+  }
+
+  sizes
+}
+
+fn shrink(constraints: &[Constraint], sizes: &mut [u16], mut excess: u16) {
+  let flexible = |constraint: &Constraint| !matches!(constraint, Constraint::Min(_));
+  for pass_flexible in [true, false] {
+    for i in (0..sizes.len()).rev() {
+      if excess == 0 {
+        return;
+      }
+      if flexible(&constraints[i]) == pass_flexible {
+        let cut = cmp::min(sizes[i], excess);
+        sizes[i] -= cut;
+        excess -= cut;
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_solves_fixed_and_fill() {
+    let constraints = [
+      Constraint::Fixed(10),
+      Constraint::Fill(1),
+      Constraint::Fixed(5),
+    ];
+    assert_eq!(solve(&constraints, 50), vec![10, 35, 5]);
+
+    let constraints = [Constraint::Fill(1), Constraint::Fill(2)];
+    assert_eq!(solve(&constraints, 10), vec![4, 6]);
+  }
+
+  #[test]
+  fn it_fills_max_before_fill() {
+    let constraints = [
+      Constraint::Fill(1),
+      Constraint::Max(80),
+      Constraint::Fill(1),
+    ];
+    assert_eq!(solve(&constraints, 120), vec![20, 80, 20]);
+    assert_eq!(solve(&constraints, 60), vec![0, 60, 0]);
+  }
+
+  #[test]
+  fn it_solves_ratio_and_min() {
+    let constraints = [Constraint::Ratio(1, 4), Constraint::Min(10)];
+    assert_eq!(solve(&constraints, 40), vec![10, 30]);
+  }
+
+  #[test]
+  fn it_caps_ratios_at_total() {
+    assert_eq!(solve(&[Constraint::Ratio(3, 1)], 30000), vec![30000]);
+    assert_eq!(
+      solve(&[Constraint::Ratio(3, 2), Constraint::Fill(1)], 40),
+      vec![40, 0]
+    );
+  }
+
+  #[test]
+  fn it_shrinks_fixed_before_min() {
+    let constraints = [
+      Constraint::Min(1),
+      Constraint::Fixed(1),
+      Constraint::Fixed(1),
+    ];
+    assert_eq!(solve(&constraints, 2), vec![1, 1, 0]);
+    assert_eq!(solve(&constraints, 1), vec![1, 0, 0]);
+    assert_eq!(solve(&constraints, 0), vec![0, 0, 0]);
+  }
+}
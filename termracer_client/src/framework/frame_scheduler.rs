@@ -0,0 +1,275 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::window::Window;
+use crate::views::view::View;
+
+// decides when frames are drawn, so that drawing keeps a steady rate however
+// often events come in
+pub struct FrameScheduler {
+  interval: Duration,
+  next_frame: Instant,
+  // when the oldest input not yet shown by a frame came in
+  pending_input: Option<Instant>,
+  timing: FrameTiming,
+}
+
+impl FrameScheduler {
+  pub fn new(fps: u32, now: Instant) -> Self {
+    assert!(
+      fps > 0,
+      "ERROR: Failed to schedule frames -- fps must be positive."
+    );
+    FrameScheduler {
+      interval: Duration::from_secs(1) / fps,
+      next_frame: now,
+      pending_input: None,
+      timing: FrameTiming::new(fps, now),
+    }
+  }
+
+  // how long events can be waited on before the next frame is due
+  pub fn time_until_frame(&self, now: Instant) -> Duration {
+    self.next_frame.saturating_duration_since(now)
+  }
+
+  pub fn is_frame_due(&self, now: Instant) -> bool {
+    now >= self.next_frame
+  }
+
+  // notes input that the next frame shows the effects of, to time how long
+  // input takes to show up
+  pub fn input_received(&mut self, time: Instant) {
+    self.pending_input.get_or_insert(time);
+  }
+
+  // records a frame drawn between two instants and schedules the next one
+  pub fn frame_done(&mut self, started: Instant, finished: Instant) {
+    let late = started.saturating_duration_since(self.next_frame) > self.interval;
+    self.timing.record(started, finished, late);
+    if let Some(time) = self.pending_input.take() {
+      self
+        .timing
+        .record_latency(finished.saturating_duration_since(time));
+    }
+
+    self.next_frame += self.interval;
+    // frames that were missed are skipped rather than drawn all at once
+    if self.next_frame < finished {
+      self.next_frame = finished;
+    }
+  }
+
+  pub fn into_timing(self) -> FrameTiming {
+    self.timing
+  }
+}
+
+// how frames have been keeping up with the target rate, for debugging
+pub struct FrameTiming {
+  target_fps: u32,
+  started: Instant,
+  frames: u32,
+  // frames started more than a frame late
+  late_frames: u32,
+  total_frame_time: Duration,
+  max_frame_time: Duration,
+  // time from input coming in to the frame showing it
+  inputs: u32,
+  total_latency: Duration,
+  max_latency: Duration,
+  // when the last frame was recorded
+  last_frame: Instant,
+}
+
+impl FrameTiming {
+  fn new(target_fps: u32, now: Instant) -> Self {
+    FrameTiming {
+      target_fps,
+      started: now,
+      frames: 0,
+      late_frames: 0,
+      total_frame_time: Duration::ZERO,
+      max_frame_time: Duration::ZERO,
+      inputs: 0,
+      total_latency: Duration::ZERO,
+      max_latency: Duration::ZERO,
+      last_frame: now,
+    }
+  }
+
+  fn record(&mut self, started: Instant, finished: Instant, late: bool) {
+    let frame_time = finished.saturating_duration_since(started);
+    self.frames += 1;
+    if late {
+      self.late_frames += 1;
+    }
+    self.total_frame_time += frame_time;
+    self.max_frame_time = self.max_frame_time.max(frame_time);
+    self.last_frame = finished;
+  }
+
+  fn record_latency(&mut self, latency: Duration) {
+    self.inputs += 1;
+    self.total_latency += latency;
+    self.max_latency = self.max_latency.max(latency);
+  }
+
+  pub fn fps(&self) -> f32 {
+    let elapsed = self.last_frame.saturating_duration_since(self.started);
+    if elapsed.is_zero() {
+      0.0
+    } else {
+      self.frames as f32 / elapsed.as_secs_f32()
+    }
+  }
+
+  pub fn average_frame_time(&self) -> Duration {
+    self
+      .total_frame_time
+      .checked_div(self.frames)
+      .unwrap_or(Duration::ZERO)
+  }
+
+  pub fn average_latency(&self) -> Duration {
+    self
+      .total_latency
+      .checked_div(self.inputs)
+      .unwrap_or(Duration::ZERO)
+  }
+}
+
+impl fmt::Display for FrameTiming {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(
+      f,
+      "frames: {}  fps: {:.1} (target {})  frame time: avg {:.2}ms, max {:.2}ms  late: {}  \
+       input latency: avg {:.2}ms, max {:.2}ms",
+      self.frames,
+      self.fps(),
+      self.target_fps,
+      self.average_frame_time().as_secs_f32() * 1000.0,
+      self.max_frame_time.as_secs_f32() * 1000.0,
+      self.late_frames,
+      self.average_latency().as_secs_f32() * 1000.0,
+      self.max_latency.as_secs_f32() * 1000.0
+    )
+  }
+}
+
+// how often a view needs drawing
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Refresh {
+  EveryFrame,
+  Every(Duration),
+  // only after whatever it shows has changed
+  OnChange,
+}
+
+// keeps track of when a view was last drawn, drawing it again only when its
+// refresh says it's due
+#[derive(Default)]
+pub struct RefreshTimer {
+  // views that haven't been drawn yet are always due
+  last_drawn: Option<Instant>,
+  // whether the view has to be drawn on the next frame regardless
+  stale: bool,
+}
+
+impl RefreshTimer {
+  // has the view drawn on the next frame, e.g. after it changed or its region
+  // was cleared
+  pub fn invalidate(&mut self) {
+    self.stale = true;
+  }
+
+  pub fn is_due(&self, refresh: Refresh, now: Instant) -> bool {
+    let Some(last_drawn) = self.last_drawn else {
+      return true;
+    };
+    match refresh {
+      _ if self.stale => true,
+      Refresh::EveryFrame => true,
+      Refresh::Every(interval) => now.saturating_duration_since(last_drawn) >= interval,
+      Refresh::OnChange => false,
+    }
+  }
+
+  pub fn try_draw<V: View>(&mut self, view: &V, window: &mut Window, now: Instant) {
+    if self.is_due(view.refresh(), now) {
+      view.draw(window);
+      self.last_drawn = Some(now);
+      self.stale = false;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_schedules_frames() {
+    let start = Instant::now();
+    let mut scheduler = FrameScheduler::new(10, start);
+    assert!(scheduler.is_frame_due(start));
+
+    scheduler.frame_done(start, start + Duration::from_millis(20));
+    let now = start + Duration::from_millis(30);
+    assert!(!scheduler.is_frame_due(now));
+    assert_eq!(scheduler.time_until_frame(now), Duration::from_millis(70));
+
+    // a slow frame doesn't lead to a burst of frames to catch up
+    let started = start + Duration::from_millis(250);
+    let finished = start + Duration::from_millis(450);
+    scheduler.frame_done(started, finished);
+    assert!(scheduler.is_frame_due(finished));
+    assert_eq!(scheduler.time_until_frame(finished), Duration::ZERO);
+    scheduler.frame_done(finished, finished);
+    assert!(!scheduler.is_frame_due(finished + Duration::from_millis(50)));
+
+    let timing = scheduler.into_timing();
+    assert_eq!(timing.frames, 3);
+    assert_eq!(timing.late_frames, 1);
+    assert_eq!(timing.max_frame_time, Duration::from_millis(200));
+  }
+
+  #[test]
+  fn it_measures_input_latency() {
+    let start = Instant::now();
+    let mut scheduler = FrameScheduler::new(10, start);
+    let at = |millis| start + Duration::from_millis(millis);
+
+    // the latency of input is measured from the first input a frame shows
+    scheduler.input_received(at(10));
+    scheduler.input_received(at(40));
+    scheduler.frame_done(at(100), at(110));
+    // frames without input don't count
+    scheduler.frame_done(at(200), at(210));
+    scheduler.input_received(at(250));
+    scheduler.frame_done(at(300), at(310));
+
+    let timing = scheduler.into_timing();
+    assert_eq!(timing.inputs, 2);
+    assert_eq!(timing.average_latency(), Duration::from_millis(80));
+    assert_eq!(timing.max_latency, Duration::from_millis(100));
+  }
+
+  #[test]
+  fn it_refreshes_views() {
+    let start = Instant::now();
+    let mut timer = RefreshTimer::default();
+    assert!(timer.is_due(Refresh::OnChange, start));
+
+    timer.last_drawn = Some(start);
+    timer.stale = false;
+    let later = start + Duration::from_millis(600);
+    assert!(!timer.is_due(Refresh::OnChange, later));
+    assert!(timer.is_due(Refresh::EveryFrame, start));
+    assert!(!timer.is_due(Refresh::Every(Duration::from_millis(500)), start));
+    assert!(timer.is_due(Refresh::Every(Duration::from_millis(500)), later));
+
+    timer.invalidate();
+    assert!(timer.is_due(Refresh::OnChange, start));
+  }
+}
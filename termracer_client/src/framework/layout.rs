@@ -1,46 +1,45 @@
-use super::rect::Rect;
-use super::split::{HorizontalSplitKind, SplitNode, VerticalSplitKind};
+use super::border::Border;
+use super::constraint::{Constraint, Direction};
+use super::rect::{Margin, Rect};
+use super::split::{HorizontalSplitKind, SplitNode, SplitSpec, VerticalSplitKind};
 
 pub struct Layout {
+  bounds: Rect,
   split_tree: SplitNode,
-  regions: Vec<Rect>,
+  // indexed by region index, with None left behind by removed regions so that
+  // the indices of the rest don't change
+  regions: Vec<Option<Rect>>,
 }
 
+// child indices leading from the root of the split tree to a node
+type Path = Vec<usize>;
+
 impl Layout {
   pub fn new(bounds: Rect) -> Self {
     Layout {
+      bounds,
       // default region covers entire window bounds
-      regions: vec![bounds],
+      regions: vec![Some(bounds)],
       split_tree: SplitNode::Leaf(0),
     }
   }
 
+  #[allow(dead_code)]
   pub fn vertical_split(
     &mut self,
     split: VerticalSplitKind,
     region_index: usize,
   ) -> (usize, usize) {
-    let region = self
-      .regions
-      .get_mut(region_index)
-      .expect("ERROR: Failed to split region -- invalid region index.");
-    let (left, right) = region.vertical_split(split);
-    *region = left;
-    self.regions.push(right);
-
-    let left_index = region_index;
-    let right_index = self.regions.len() - 1;
-
-    let split_node = self
-      .get_split_leaf_mut(region_index)
-      .expect("ERROR: Failed to split region -- could not find split leaf.");
-    *split_node = SplitNode::Vertical {
-      kind: split,
-      left: Box::new(SplitNode::Leaf(left_index)),
-      right: Box::new(SplitNode::Leaf(right_index)),
-    };
-
-    (left_index, right_index)
+    let right_index = self.regions.len();
+    self.split_leaf(
+      region_index,
+      SplitNode::Vertical {
+        kind: split,
+        left: Box::new(SplitNode::Leaf(region_index)),
+        right: Box::new(SplitNode::Leaf(right_index)),
+      },
+    );
+    (region_index, right_index)
   }
 
   pub fn horizontal_split(
@@ -48,93 +47,380 @@ impl Layout {
     split: HorizontalSplitKind,
     region_index: usize,
   ) -> (usize, usize) {
-    let region = self
-      .regions
-      .get_mut(region_index)
-      .expect("ERROR: Failed to split region -- invalid region index.");
-    let (top, bottom) = region.horizontal_split(split);
-    *region = top;
-    self.regions.push(bottom);
+    let bottom_index = self.regions.len();
+    self.split_leaf(
+      region_index,
+      SplitNode::Horizontal {
+        kind: split,
+        top: Box::new(SplitNode::Leaf(region_index)),
+        bottom: Box::new(SplitNode::Leaf(bottom_index)),
+      },
+    );
+    (region_index, bottom_index)
+  }
 
-    let top_index = region_index;
-    let bottom_index = self.regions.len() - 1;
+  // splits a region into one region per constraint, the first of which keeps
+  // the index of the region
+  pub fn split(
+    &mut self,
+    direction: Direction,
+    constraints: &[Constraint],
+    region_index: usize,
+  ) -> Vec<usize> {
+    assert!(
+      !constraints.is_empty(),
+      "ERROR: Failed to split region -- no constraints."
+    );
+    let mut region_indices = vec![region_index];
+    region_indices.extend(self.regions.len()..self.regions.len() + constraints.len() - 1);
+    self.split_leaf(
+      region_index,
+      SplitNode::Split {
+        direction,
+        constraints: constraints.to_vec(),
+        children: region_indices.iter().map(|&i| SplitNode::Leaf(i)).collect(),
+      },
+    );
+    region_indices
+  }
 
-    let split_node = self
-      .get_split_leaf_mut(region_index)
-      .expect("ERROR: Failed to split region --could not find split leaf.");
-    *split_node = SplitNode::Horizontal {
-      kind: split,
-      top: Box::new(SplitNode::Leaf(top_index)),
-      bottom: Box::new(SplitNode::Leaf(bottom_index)),
+  // shrinks a region, leaving a margin empty around it
+  pub fn inset(&mut self, margin: Margin, region_index: usize) {
+    self.split_leaf(
+      region_index,
+      SplitNode::Inset {
+        margin,
+        child: Box::new(SplitNode::Leaf(region_index)),
+      },
+    );
+  }
+
+  // shrinks a region to at most a maximum size, centered in its old bounds
+  pub fn center(&mut self, max_width: u16, max_height: u16, region_index: usize) {
+    self.split_leaf(
+      region_index,
+      SplitNode::Centered {
+        max_width,
+        max_height,
+        child: Box::new(SplitNode::Leaf(region_index)),
+      },
+    );
+  }
+
+  // shrinks a region by a cell on each side, leaving room for a border
+  pub fn border(&mut self, border: Border, region_index: usize) {
+    self.split_leaf(
+      region_index,
+      SplitNode::Bordered {
+        border,
+        child: Box::new(SplitNode::Leaf(region_index)),
+      },
+    );
+  }
+
+  // swaps the border around a region for another of the same size
+  pub fn set_border(&mut self, border: Border, region_index: usize) {
+    let path = find_border(&self.split_tree, region_index)
+      .expect("ERROR: Failed to set border -- region has no border.");
+    if let SplitNode::Bordered { border: old, .. } = node_at_mut(&mut self.split_tree, &path) {
+      *old = border;
+    }
+  }
+
+  // removes a region, giving its space to its siblings in the split that
+  // created it, and returns the regions that grew
+  pub fn remove(&mut self, region_index: usize) -> Vec<usize> {
+    let path = find_removable(&self.split_tree, region_index)
+      .expect("ERROR: Failed to remove region -- invalid region index.");
+    let (&child, parent_path) = path
+      .split_last()
+      .expect("ERROR: Failed to remove region -- cannot remove the only region.");
+
+    let parent = node_at_mut(&mut self.split_tree, parent_path);
+    let removed = match parent {
+      SplitNode::Split {
+        constraints,
+        children,
+        ..
+      } if children.len() > 2 => {
+        constraints.remove(child);
+        children.remove(child)
+      }
+      // a split left with one child is replaced by that child
+      _ => {
+        let mut children: Vec<SplitNode> = parent
+          .children_mut()
+          .into_iter()
+          .map(|node| std::mem::replace(node, SplitNode::Leaf(region_index)))
+          .collect();
+        let removed = children.remove(child);
+        *parent = children
+          .pop()
+          .expect("ERROR: Failed to remove region -- split has no siblings.");
+        removed
+      }
     };
+    for leaf in removed.leaves() {
+      self.regions[leaf] = None;
+    }
+
+    let bounds = bounds_at(&self.split_tree, self.bounds, parent_path);
+    let parent = node_at_mut(&mut self.split_tree, parent_path);
+    place(&mut self.regions, parent, bounds);
+    parent.leaves()
+  }
 
-    (region_index, self.regions.len() - 1)
+  // replaces everything a region was split into (or just the region, if it
+  // wasn't split) with regions built from a description, returning their
+  // indices in order -- the first keeps the index of the region
+  pub fn replace(&mut self, region_index: usize, spec: &SplitSpec) -> Vec<usize> {
+    let path = find_subtree(&self.split_tree, region_index)
+      .expect("ERROR: Failed to replace region -- invalid region index.");
+    let bounds = bounds_at(&self.split_tree, self.bounds, &path);
+    for leaf in node_at_mut(&mut self.split_tree, &path).leaves() {
+      self.regions[leaf] = None;
+    }
+
+    let node = self.build(spec, bounds, &mut Some(region_index));
+    let leaves = node.leaves();
+    *node_at_mut(&mut self.split_tree, &path) = node;
+    leaves
   }
 
-  pub fn regions(&self) -> &Vec<Rect> {
-    &self.regions
+  // throws away every region, building new ones from a description, and
+  // returns their indices in order
+  pub fn rebuild(&mut self, spec: &SplitSpec) -> Vec<usize> {
+    self.regions.clear();
+    self.split_tree = self.build(spec, self.bounds, &mut None);
+    self.split_tree.leaves()
   }
 
   pub fn region(&self, region_index: usize) -> Option<&Rect> {
-    self.regions.get(region_index)
+    self.regions.get(region_index).and_then(Option::as_ref)
+  }
+
+  // every border in the layout along with the bounds it's drawn around the
+  // edge of
+  pub fn borders(&self) -> Vec<(Rect, &Border)> {
+    fn helper<'a>(node: &'a SplitNode, bounds: Rect, borders: &mut Vec<(Rect, &'a Border)>) {
+      if let SplitNode::Bordered { border, .. } = node {
+        borders.push((bounds, border));
+      }
+      for (child, child_bounds) in node.children().into_iter().zip(node.child_bounds(bounds)) {
+        helper(child, child_bounds, borders);
+      }
+    }
+    let mut borders = vec![];
+    helper(&self.split_tree, self.bounds, &mut borders);
+    borders
   }
 
   pub fn resize(&mut self, new_bounds: Rect) {
-    fn helper(regions: &mut Vec<Rect>, node: &SplitNode, bounds: Rect) {
-      match node {
-        SplitNode::Vertical {
-          kind,
-          ref left,
-          ref right,
-        } => {
-          let (left_bounds, right_bounds) = bounds.vertical_split(*kind);
-          helper(regions, left, left_bounds);
-          helper(regions, right, right_bounds);
-        }
-        SplitNode::Horizontal {
-          kind,
-          ref top,
-          ref bottom,
-        } => {
-          let (top_bounds, bottom_bounds) = bounds.horizontal_split(*kind);
-          helper(regions, top, top_bounds);
-          helper(regions, bottom, bottom_bounds);
-        }
-        SplitNode::Leaf(i) => regions[*i] = bounds,
+    self.bounds = new_bounds;
+    place(&mut self.regions, &self.split_tree, new_bounds);
+  }
+
+  // swaps the leaf of a region for a split of it, creating any new regions
+  fn split_leaf(&mut self, region_index: usize, node: SplitNode) {
+    let bounds = *self
+      .region(region_index)
+      .expect("ERROR: Failed to split region -- invalid region index.");
+    for i in node.leaves() {
+      if i >= self.regions.len() {
+        self.regions.push(None);
       }
     }
-    helper(&mut self.regions, &self.split_tree, new_bounds);
+
+    place(&mut self.regions, &node, bounds);
+
+    let split_node = self
+      .get_split_leaf_mut(region_index)
+      .expect("ERROR: Failed to split region -- could not find split leaf.");
+    *split_node = node;
+  }
+
+  // creates regions for a description within bounds, reusing an index for
+  // the first region if one is given
+  fn build(
+    &mut self,
+    spec: &SplitSpec,
+    bounds: Rect,
+    reuse_index: &mut Option<usize>,
+  ) -> SplitNode {
+    // the bounds of children are worked out by the node itself, so the node is
+    // created with placeholder children first
+    let placeholder =
+      |count: usize| -> Vec<SplitNode> { (0..count).map(SplitNode::Leaf).collect() };
+    let (mut node, child_specs): (SplitNode, Vec<&SplitSpec>) = match spec {
+      SplitSpec::Split {
+        direction,
+        constraints,
+        children,
+      } => {
+        assert_eq!(
+          constraints.len(),
+          children.len(),
+          "ERROR: Failed to build layout -- every child needs one constraint."
+        );
+        (
+          SplitNode::Split {
+            direction: *direction,
+            constraints: constraints.clone(),
+            children: placeholder(children.len()),
+          },
+          children.iter().collect(),
+        )
+      }
+      SplitSpec::Bordered { border, child } => (
+        SplitNode::Bordered {
+          border: border.clone(),
+          child: Box::new(SplitNode::Leaf(0)),
+        },
+        vec![child],
+      ),
+      SplitSpec::Region => {
+        return match reuse_index.take() {
+          Some(i) => {
+            self.regions[i] = Some(bounds);
+            SplitNode::Leaf(i)
+          }
+          None => {
+            self.regions.push(Some(bounds));
+            SplitNode::Leaf(self.regions.len() - 1)
+          }
+        };
+      }
+    };
+
+    let child_bounds = node.child_bounds(bounds);
+    for ((child, child_spec), child_bounds) in node
+      .children_mut()
+      .into_iter()
+      .zip(child_specs)
+      .zip(child_bounds)
+    {
+      *child = self.build(child_spec, child_bounds, reuse_index);
+    }
+    node
   }
 
   fn get_split_leaf_mut(&mut self, region_index: usize) -> Option<&mut SplitNode> {
     fn helper(node: &mut SplitNode, target: usize) -> Option<&mut SplitNode> {
-      match node {
-        &mut SplitNode::Vertical {
-          kind: _,
-          ref mut left,
-          ref mut right,
-        } => helper(left, target).or_else(|| helper(right, target)),
-        &mut SplitNode::Horizontal {
-          kind: _,
-          ref mut top,
-          ref mut bottom,
-        } => helper(top, target).or_else(|| helper(bottom, target)),
-        SplitNode::Leaf(i) => {
-          if *i == target {
-            Some(node)
-          } else {
-            None
-          }
-        }
+      if let SplitNode::Leaf(i) = node {
+        return if *i == target { Some(node) } else { None };
       }
+      node
+        .children_mut()
+        .into_iter()
+        .find_map(|child| helper(child, target))
     }
     helper(&mut self.split_tree, region_index)
   }
 }
 
+fn node_at_mut<'a>(root: &'a mut SplitNode, path: &[usize]) -> &'a mut SplitNode {
+  path.iter().fold(root, |node, &i| {
+    node
+      .children_mut()
+      .into_iter()
+      .nth(i)
+      .expect("ERROR: Invalid split path.")
+  })
+}
+
+fn bounds_at(root: &SplitNode, bounds: Rect, path: &[usize]) -> Rect {
+  let mut node = root;
+  let mut bounds = bounds;
+  for &i in path {
+    bounds = node.child_bounds(bounds)[i];
+    node = node.children()[i];
+  }
+  bounds
+}
+
+// path to the node holding a region that can be removed from its parent,
+// which is the region's leaf along with anything only wrapping it
+fn find_removable(node: &SplitNode, target: usize) -> Option<Path> {
+  fn only_holds(node: &SplitNode, target: usize) -> bool {
+    match node {
+      SplitNode::Leaf(i) => *i == target,
+      SplitNode::Inset { child, .. }
+      | SplitNode::Centered { child, .. }
+      | SplitNode::Bordered { child, .. } => only_holds(child, target),
+      // a split into a single region goes along with it
+      SplitNode::Split { children, .. } if children.len() == 1 => only_holds(&children[0], target),
+      _ => false,
+    }
+  }
+
+  if only_holds(node, target) {
+    return Some(vec![]);
+  }
+  node
+    .children()
+    .into_iter()
+    .enumerate()
+    .find_map(|(i, child)| {
+      let mut path = find_removable(child, target)?;
+      path.insert(0, i);
+      Some(path)
+    })
+}
+
+// path to the innermost border around a region and everything split off
+// from it
+fn find_border(node: &SplitNode, target: usize) -> Option<Path> {
+  let inner = node
+    .children()
+    .into_iter()
+    .enumerate()
+    .find_map(|(i, child)| {
+      let mut path = find_border(child, target)?;
+      path.insert(0, i);
+      Some(path)
+    });
+  match node {
+    SplitNode::Bordered { child, .. } if inner.is_none() && child.first_leaf() == target => {
+      Some(vec![])
+    }
+    _ => inner,
+  }
+}
+
+// path to the highest node that the region is the first leaf of, which holds
+// every region split off from it
+fn find_subtree(node: &SplitNode, target: usize) -> Option<Path> {
+  if node.first_leaf() == target {
+    return Some(vec![]);
+  }
+  node
+    .children()
+    .into_iter()
+    .enumerate()
+    .find_map(|(i, child)| {
+      let mut path = find_subtree(child, target)?;
+      path.insert(0, i);
+      Some(path)
+    })
+}
+
+// sets the bounds of every region in a tree, splitting the bounds the same
+// way the tree was split
+fn place(regions: &mut [Option<Rect>], node: &SplitNode, bounds: Rect) {
+  match node {
+    SplitNode::Leaf(i) => regions[*i] = Some(bounds),
+    node => {
+      for (child, child_bounds) in node.children().into_iter().zip(node.child_bounds(bounds)) {
+        place(regions, child, child_bounds);
+      }
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::framework::border::BorderStyle;
   use crate::framework::coord::Coord;
 
   #[test]
@@ -147,8 +433,8 @@ mod tests {
     let (left, right) = layout.vertical_split(VerticalSplitKind::CellsInLeft(30), 0);
 
     assert_eq!((left, right), (0, 1));
-    assert_eq!(layout.regions[left].width, 30);
-    assert_eq!(layout.regions[right].width, 70);
+    assert_eq!(layout.regions[left].unwrap().width, 30);
+    assert_eq!(layout.regions[right].unwrap().width, 70);
   }
 
   #[test]
@@ -161,8 +447,8 @@ mod tests {
     let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(30), 0);
 
     assert_eq!((top, bottom), (0, 1));
-    assert_eq!(layout.regions[top].height, 30);
-    assert_eq!(layout.regions[bottom].height, 20);
+    assert_eq!(layout.regions[top].unwrap().height, 30);
+    assert_eq!(layout.regions[bottom].unwrap().height, 20);
   }
 
   #[test]
@@ -208,7 +494,7 @@ mod tests {
     });
 
     assert_eq!(
-      layout.regions[top],
+      layout.regions[top].unwrap(),
       Rect {
         coord: Coord { row: 0, col: 0 },
         width: 75,
@@ -216,7 +502,7 @@ mod tests {
       }
     );
     assert_eq!(
-      layout.regions[bottom_left],
+      layout.regions[bottom_left].unwrap(),
       Rect {
         coord: Coord { row: 20, col: 0 },
         width: 30,
@@ -224,7 +510,7 @@ mod tests {
       }
     );
     assert_eq!(
-      layout.regions[bottom_right],
+      layout.regions[bottom_right].unwrap(),
       Rect {
         coord: Coord { row: 20, col: 30 },
         width: 45,
@@ -232,4 +518,236 @@ mod tests {
       }
     );
   }
+
+  fn create_layout() -> Layout {
+    Layout::new(Rect {
+      coord: Coord { row: 0, col: 0 },
+      width: 100,
+      height: 50,
+    })
+  }
+
+  #[test]
+  fn it_removes_regions() {
+    let mut layout = create_layout();
+    let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(20), 0);
+    let (bottom_left, bottom_right) =
+      layout.vertical_split(VerticalSplitKind::CellsInLeft(30), bottom);
+
+    assert_eq!(layout.remove(top), vec![bottom_left, bottom_right]);
+
+    assert_eq!(layout.region(top), None);
+    assert_eq!(layout.split_tree.leaves(), vec![bottom_left, bottom_right]);
+    assert_eq!(
+      layout.region(bottom_left),
+      Some(&Rect {
+        coord: Coord { row: 0, col: 0 },
+        width: 30,
+        height: 50
+      })
+    );
+
+    assert_eq!(layout.remove(bottom_left), vec![bottom_right]);
+    assert_eq!(layout.split_tree, SplitNode::Leaf(bottom_right));
+    assert_eq!(layout.region(bottom_right).unwrap().width, 100);
+
+    // the remaining regions can still be split
+    let (_, new) = layout.vertical_split(VerticalSplitKind::CellsInLeft(10), bottom_right);
+    assert_eq!(new, 3);
+  }
+
+  #[test]
+  fn it_removes_the_only_region_of_a_split() {
+    let mut layout = create_layout();
+    let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(20), 0);
+    let spec = SplitSpec::split(
+      Direction::Horizontal,
+      vec![(Constraint::Fill(1), SplitSpec::Region)],
+    );
+    assert_eq!(layout.replace(bottom, &spec), vec![bottom]);
+
+    // the split goes along with its only region
+    assert_eq!(layout.remove(bottom), vec![top]);
+    assert_eq!(layout.split_tree, SplitNode::Leaf(top));
+    assert_eq!(layout.region(top).unwrap().height, 50);
+  }
+
+  #[test]
+  fn it_replaces_subtrees() {
+    let mut layout = create_layout();
+    let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(20), 0);
+    layout.vertical_split(VerticalSplitKind::CellsInLeft(30), bottom);
+
+    let spec = SplitSpec::split(
+      Direction::Vertical,
+      vec![
+        (Constraint::Fixed(10), SplitSpec::Region),
+        (Constraint::Fill(1), SplitSpec::Region),
+      ],
+    );
+    let regions = layout.replace(bottom, &spec);
+
+    assert_eq!(regions, vec![bottom, 3]);
+    assert_eq!(layout.region(2), None);
+    assert_eq!(layout.split_tree.leaves(), vec![top, bottom, 3]);
+    assert_eq!(
+      layout.region(3),
+      Some(&Rect {
+        coord: Coord { row: 30, col: 0 },
+        width: 100,
+        height: 20
+      })
+    );
+  }
+
+  #[test]
+  fn it_rebuilds() {
+    let mut layout = create_layout();
+    layout.horizontal_split(HorizontalSplitKind::CellsInTop(20), 0);
+
+    let spec = SplitSpec::split(
+      Direction::Horizontal,
+      vec![
+        (Constraint::Ratio(1, 2), SplitSpec::Region),
+        (
+          Constraint::Fill(1),
+          SplitSpec::split(
+            Direction::Vertical,
+            vec![
+              (Constraint::Fill(1), SplitSpec::Region),
+              (Constraint::Fixed(5), SplitSpec::Region),
+            ],
+          ),
+        ),
+      ],
+    );
+
+    assert_eq!(layout.rebuild(&spec), vec![0, 1, 2]);
+    assert_eq!(layout.region(0).unwrap().width, 50);
+    assert_eq!(
+      layout.region(2),
+      Some(&Rect {
+        coord: Coord { row: 45, col: 50 },
+        width: 50,
+        height: 5
+      })
+    );
+  }
+
+  #[test]
+  fn it_splits_by_constraints() {
+    let mut layout = create_layout();
+    let regions = layout.split(
+      Direction::Horizontal,
+      &[
+        Constraint::Fixed(10),
+        Constraint::Fill(1),
+        Constraint::Max(20),
+      ],
+      0,
+    );
+    assert_eq!(regions, vec![0, 1, 2]);
+    layout.inset(Margin::symmetric(5, 10), 1);
+
+    assert_eq!(
+      layout.region(1),
+      Some(&Rect {
+        coord: Coord { row: 5, col: 20 },
+        width: 50,
+        height: 40
+      })
+    );
+
+    // the other regions share the space of a removed one
+    assert_eq!(layout.remove(2), vec![0, 1]);
+    assert_eq!(layout.region(1).unwrap().width, 70);
+
+    layout.resize(Rect {
+      coord: Coord { row: 0, col: 0 },
+      width: 40,
+      height: 20,
+    });
+    assert_eq!(
+      layout.region(1),
+      Some(&Rect {
+        coord: Coord { row: 5, col: 20 },
+        width: 10,
+        height: 10
+      })
+    );
+  }
+
+  #[test]
+  fn it_splits_into_one_region() {
+    let mut layout = create_layout();
+    let spec = SplitSpec::split(
+      Direction::Horizontal,
+      vec![(Constraint::Ratio(3, 2), SplitSpec::Region)],
+    );
+
+    let bounds = layout.bounds;
+    assert_eq!(layout.replace(0, &spec), vec![0]);
+    assert_eq!(layout.region(0), Some(&bounds));
+  }
+
+  #[test]
+  fn it_centers_regions() {
+    let mut layout = create_layout();
+    let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(10), 0);
+    layout.center(20, 4, bottom);
+
+    assert_eq!(
+      layout.region(bottom),
+      Some(&Rect {
+        coord: Coord { row: 28, col: 40 },
+        width: 20,
+        height: 4
+      })
+    );
+
+    // removing the centered region removes the centering along with it
+    assert_eq!(layout.remove(bottom), vec![top]);
+    assert_eq!(layout.split_tree, SplitNode::Leaf(top));
+  }
+
+  #[test]
+  fn it_borders_regions() {
+    let mut layout = create_layout();
+    let (top, bottom) = layout.horizontal_split(HorizontalSplitKind::CellsInTop(10), 0);
+    layout.border(Border::new(BorderStyle::Single), bottom);
+
+    assert_eq!(
+      layout.region(bottom),
+      Some(&Rect {
+        coord: Coord { row: 11, col: 1 },
+        width: 98,
+        height: 38
+      })
+    );
+
+    // the border follows its region around as the layout is resized
+    layout.resize(Rect {
+      coord: Coord { row: 0, col: 0 },
+      width: 50,
+      height: 20,
+    });
+    assert_eq!(layout.region(bottom).unwrap().width, 48);
+    let mut border = Border::new(BorderStyle::Rounded);
+    border.title = Some("Title".to_owned());
+    layout.set_border(border.clone(), bottom);
+    assert_eq!(
+      layout.borders(),
+      vec![(
+        Rect {
+          coord: Coord { row: 10, col: 0 },
+          width: 50,
+          height: 10
+        },
+        &border
+      )]
+    );
+
+    assert_eq!(layout.remove(bottom), vec![top]);
+    assert!(layout.borders().is_empty());
+  }
 }
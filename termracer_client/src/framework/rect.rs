@@ -1,5 +1,6 @@
 use std::cmp::min;
 
+use super::constraint::{self, Constraint, Direction};
 use super::coord::Coord;
 use super::split::{HorizontalSplitKind, VerticalSplitKind};
 
@@ -10,7 +11,102 @@ pub struct Rect {
   pub height: u16,
 }
 
+// space left empty around the inside of a rect
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Margin {
+  pub top: u16,
+  pub right: u16,
+  pub bottom: u16,
+  pub left: u16,
+}
+
+impl Margin {
+  pub fn uniform(cells: u16) -> Self {
+    Margin {
+      top: cells,
+      right: cells,
+      bottom: cells,
+      left: cells,
+    }
+  }
+
+  pub fn symmetric(vertical: u16, horizontal: u16) -> Self {
+    Margin {
+      top: vertical,
+      right: horizontal,
+      bottom: vertical,
+      left: horizontal,
+    }
+  }
+}
+
 impl Rect {
+  // splits into one rect per constraint, side by side or stacked
+  pub fn split(&self, direction: Direction, constraints: &[Constraint]) -> Vec<Rect> {
+    let total = match direction {
+      Direction::Horizontal => self.width,
+      Direction::Vertical => self.height,
+    };
+    let mut offset = 0;
+    constraint::solve(constraints, total)
+      .into_iter()
+      .map(|size| {
+        let rect = match direction {
+          Direction::Horizontal => Rect {
+            coord: self.coord
+              + Coord {
+                row: 0,
+                col: offset,
+              },
+            width: size,
+            height: self.height,
+          },
+          Direction::Vertical => Rect {
+            coord: self.coord
+              + Coord {
+                row: offset,
+                col: 0,
+              },
+            width: self.width,
+            height: size,
+          },
+        };
+        offset += size;
+        rect
+      })
+      .collect()
+  }
+
+  // the rect inside a margin, which shrinks to nothing rather than overflowing
+  pub fn inset(&self, margin: Margin) -> Rect {
+    let left = min(margin.left, self.width);
+    let top = min(margin.top, self.height);
+    Rect {
+      coord: self.coord
+        + Coord {
+          row: top,
+          col: left,
+        },
+      width: (self.width - left).saturating_sub(margin.right),
+      height: (self.height - top).saturating_sub(margin.bottom),
+    }
+  }
+
+  // a rect in the middle, as big as it can be up to a maximum size
+  pub fn centered(&self, max_width: u16, max_height: u16) -> Rect {
+    let width = min(max_width, self.width);
+    let height = min(max_height, self.height);
+    Rect {
+      coord: self.coord
+        + Coord {
+          row: (self.height - height) / 2,
+          col: (self.width - width) / 2,
+        },
+      width,
+      height,
+    }
+  }
+
   pub fn vertical_split(&self, split: VerticalSplitKind) -> (Rect, Rect) {
     let helper = |cells_in_left: u16| -> (Rect, Rect) {
       let left = Rect {
@@ -370,4 +466,81 @@ mod tests {
       }
     );
   }
+
+  #[test]
+  fn it_splits_by_constraints() {
+    let r = Rect {
+      coord: Coord { row: 5, col: 10 },
+      width: 100,
+      height: 3,
+    };
+
+    let rects = r.split(
+      Direction::Horizontal,
+      &[
+        Constraint::Fixed(20),
+        Constraint::Fill(1),
+        Constraint::Fixed(30),
+      ],
+    );
+    assert_eq!(
+      rects,
+      vec![
+        Rect {
+          coord: Coord { row: 5, col: 10 },
+          width: 20,
+          height: 3
+        },
+        Rect {
+          coord: Coord { row: 5, col: 30 },
+          width: 50,
+          height: 3
+        },
+        Rect {
+          coord: Coord { row: 5, col: 80 },
+          width: 30,
+          height: 3
+        },
+      ]
+    );
+
+    // the minimum size survives when there isn't room for everything
+    let rects = r.split(
+      Direction::Vertical,
+      &[
+        Constraint::Min(2),
+        Constraint::Fixed(1),
+        Constraint::Fixed(1),
+      ],
+    );
+    let heights: Vec<u16> = rects.iter().map(|rect| rect.height).collect();
+    assert_eq!(heights, vec![2, 1, 0]);
+  }
+
+  #[test]
+  fn it_insets_and_centers() {
+    let r = Rect {
+      coord: Coord { row: 0, col: 0 },
+      width: 10,
+      height: 6,
+    };
+
+    assert_eq!(
+      r.inset(Margin::symmetric(1, 2)),
+      Rect {
+        coord: Coord { row: 1, col: 2 },
+        width: 6,
+        height: 4
+      }
+    );
+    assert_eq!(r.inset(Margin::uniform(20)).width, 0);
+    assert_eq!(
+      r.centered(4, 20),
+      Rect {
+        coord: Coord { row: 0, col: 3 },
+        width: 4,
+        height: 6
+      }
+    );
+  }
 }
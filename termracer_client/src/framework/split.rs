@@ -1,3 +1,7 @@
+use super::border::Border;
+use super::constraint::{Constraint, Direction};
+use super::rect::{Margin, Rect};
+
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HorizontalSplitKind {
@@ -29,5 +33,138 @@ pub enum SplitNode {
     top: Box<SplitNode>,
     bottom: Box<SplitNode>,
   },
+  // any number of children, sized by one constraint each
+  Split {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+    children: Vec<SplitNode>,
+  },
+  Inset {
+    margin: Margin,
+    child: Box<SplitNode>,
+  },
+  Centered {
+    max_width: u16,
+    max_height: u16,
+    child: Box<SplitNode>,
+  },
+  // a region shrunk by a cell on each side, with a border drawn around it
+  Bordered {
+    border: Border,
+    child: Box<SplitNode>,
+  },
   Leaf(usize),
 }
+
+impl SplitNode {
+  pub fn children(&self) -> Vec<&SplitNode> {
+    match self {
+      SplitNode::Vertical { left, right, .. } => vec![left, right],
+      SplitNode::Horizontal { top, bottom, .. } => vec![top, bottom],
+      SplitNode::Split { children, .. } => children.iter().collect(),
+      SplitNode::Inset { child, .. }
+      | SplitNode::Centered { child, .. }
+      | SplitNode::Bordered { child, .. } => vec![child],
+      SplitNode::Leaf(_) => vec![],
+    }
+  }
+
+  pub fn children_mut(&mut self) -> Vec<&mut SplitNode> {
+    match self {
+      SplitNode::Vertical { left, right, .. } => vec![left, right],
+      SplitNode::Horizontal { top, bottom, .. } => vec![top, bottom],
+      SplitNode::Split { children, .. } => children.iter_mut().collect(),
+      SplitNode::Inset { child, .. }
+      | SplitNode::Centered { child, .. }
+      | SplitNode::Bordered { child, .. } => vec![child],
+      SplitNode::Leaf(_) => vec![],
+    }
+  }
+
+  // bounds of each child, in the same order as children
+  pub fn child_bounds(&self, bounds: Rect) -> Vec<Rect> {
+    match self {
+      SplitNode::Vertical { kind, .. } => {
+        let (left, right) = bounds.vertical_split(*kind);
+        vec![left, right]
+      }
+      SplitNode::Horizontal { kind, .. } => {
+        let (top, bottom) = bounds.horizontal_split(*kind);
+        vec![top, bottom]
+      }
+      SplitNode::Split {
+        direction,
+        constraints,
+        ..
+      } => bounds.split(*direction, constraints),
+      SplitNode::Inset { margin, .. } => vec![bounds.inset(*margin)],
+      SplitNode::Centered {
+        max_width,
+        max_height,
+        ..
+      } => vec![bounds.centered(*max_width, *max_height)],
+      SplitNode::Bordered { .. } => vec![bounds.inset(Margin::uniform(1))],
+      SplitNode::Leaf(_) => vec![],
+    }
+  }
+
+  // region indices of the leaves, from top to bottom and left to right
+  pub fn leaves(&self) -> Vec<usize> {
+    match self {
+      SplitNode::Leaf(i) => vec![*i],
+      node => node
+        .children()
+        .into_iter()
+        .flat_map(SplitNode::leaves)
+        .collect(),
+    }
+  }
+
+  // the region that was split to create this node, which keeps its index as
+  // the top or left of the split
+  pub fn first_leaf(&self) -> usize {
+    match self {
+      SplitNode::Leaf(i) => *i,
+      node => node
+        .children()
+        .first()
+        .expect("ERROR: Split has no children.")
+        .first_leaf(),
+    }
+  }
+}
+
+// a description of how to split up a region, built into a SplitNode by the
+// layout, which gives out the region indices
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SplitSpec {
+  Split {
+    direction: Direction,
+    constraints: Vec<Constraint>,
+    children: Vec<SplitSpec>,
+  },
+  Bordered {
+    border: Border,
+    child: Box<SplitSpec>,
+  },
+  Region,
+}
+
+impl SplitSpec {
+  // children paired with the constraints on their sizes
+  pub fn split(direction: Direction, children: Vec<(Constraint, SplitSpec)>) -> Self {
+    let (constraints, children) = children.into_iter().unzip();
+    SplitSpec::Split {
+      direction,
+      constraints,
+      children,
+    }
+  }
+
+  pub fn bordered(border: Border, child: SplitSpec) -> Self {
+    SplitSpec::Bordered {
+      border,
+      child: Box::new(child),
+    }
+  }
+}
@@ -5,10 +5,12 @@ use crossterm::{cursor, queue, QueueableCommand};
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+use super::border::{self, Border};
+use super::constraint::{Constraint, Direction};
 use super::coord::Coord;
 use super::layout::Layout;
-use super::rect::Rect;
-use super::split::{HorizontalSplitKind, VerticalSplitKind};
+use super::rect::{Margin, Rect};
+use super::split::{HorizontalSplitKind, SplitSpec, VerticalSplitKind};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 struct Cell {
@@ -32,10 +34,12 @@ type Buffer = Vec<Vec<Cell>>;
 pub struct Window {
   // bounds of entire window
   bounds: Rect,
-  layout: Layout,
+  // one layout for each screen, the last of which is being drawn to
+  layouts: Vec<Layout>,
   buffer: Buffer,
   dirty: Vec<Vec<bool>>,
   cursor_pos: Coord,
+  cursor_visible: bool,
 }
 
 impl Window {
@@ -47,64 +51,113 @@ impl Window {
     };
     Window {
       bounds,
-      layout: Layout::new(bounds),
+      layouts: vec![Layout::new(bounds)],
       buffer: vec![vec![Cell::new(); width as usize]; height as usize],
       dirty: vec![vec![false; width as usize]; height as usize],
       cursor_pos: Coord { row: 0, col: 0 },
+      cursor_visible: true,
     }
   }
 
   // row and column are relative to region
   pub fn draw(&mut self, s: &str, fg: Color, bg: Color, region_coord: Coord, region_index: usize) {
-    let chars: Vec<&str> = s.graphemes(true).collect();
-    assert!(
-      chars.iter().all(|c| c.width() == 1),
-      "ERROR: Window only supports drawing characters with width == 1."
-    );
-    let region_bounds = self
-      .layout
+    let region_bounds = *self
+      .layout()
       .region(region_index)
       .expect("ERROR: Failed to draw -- invalid region index.");
+    self.draw_within(s, fg, bg, region_coord, region_bounds);
+  }
 
-    for (dcol, c) in chars.into_iter().enumerate() {
-      if self.check_coord(
-        region_coord.row,
-        region_coord.col + (dcol as u16),
-        region_index,
-      ) {
-        let window_row = region_bounds.coord.row + region_coord.row;
-        let window_column = region_bounds.coord.col + region_coord.col + (dcol as u16);
+  // draws anywhere within bounds, with row and column relative to them
+  fn draw_within(
+    &mut self,
+    s: &str,
+    fg: Color,
+    bg: Color,
+    region_coord: Coord,
+    region_bounds: Rect,
+  ) {
+    let mut dcol = 0;
+    for c in s.graphemes(true) {
+      let width = c.width() as u16;
+      // graphemes that take up no room, such as a stray combining mark or
+      // joiner, can't be given a cell of their own
+      if width == 0 {
+        continue;
+      }
+      // cells only hold characters up to two columns wide, so anything wider
+      // is drawn as blanks over the columns it would cover
+      if width > 2 {
+        self.draw_within(
+          &" ".repeat(width as usize),
+          fg,
+          bg,
+          Coord {
+            row: region_coord.row,
+            col: region_coord.col + dcol,
+          },
+          region_bounds,
+        );
+        dcol += width;
+        continue;
+      }
+      let region_column = region_coord.col + dcol;
+      dcol += width;
+
+      if !self.check_coord(region_coord.row, region_column, region_bounds) {
+        continue;
+      }
+      let window_row = region_bounds.coord.row + region_coord.row;
+      let window_column = region_bounds.coord.col + region_column;
 
-        let cell = &mut self.buffer[window_row as usize][window_column as usize];
-        let new_cell = Cell {
+      // wide characters that would be cut off are replaced with a blank
+      let fits = self.check_coord(region_coord.row, region_column + width - 1, region_bounds);
+      let c = if fits { c } else { " " };
+
+      self.set_cell(
+        window_row,
+        window_column,
+        Cell {
           c: c.to_string(),
           fg,
           bg,
-        };
-
-        if cell != &new_cell {
-          *cell = new_cell;
-          self.dirty[window_row as usize][window_column as usize] = true;
-        }
+        },
+      );
+      if fits && width == 2 {
+        // the cell covered by the second half of a wide character is left
+        // empty so that it isn't printed
+        self.set_cell(
+          window_row,
+          window_column + 1,
+          Cell {
+            c: String::new(),
+            fg,
+            bg,
+          },
+        );
       }
     }
   }
 
   pub fn set_cursor(&mut self, region_coord: Coord, region_index: usize) {
     let region = self
-      .layout
+      .layout()
       .region(region_index)
       .expect("ERROR: Failed to set cursor position -- invalid region index.");
     self.cursor_pos = region.coord + region_coord;
   }
 
+  pub fn set_cursor_visible(&mut self, visible: bool) {
+    self.cursor_visible = visible;
+  }
+
   #[allow(dead_code)]
   pub fn vertical_split(
     &mut self,
     split: VerticalSplitKind,
     region_index: usize,
   ) -> (usize, usize) {
-    self.layout.vertical_split(split, region_index)
+    self.layout_mut().vertical_split(split, region_index)
   }
 
   pub fn horizontal_split(
@@ -112,7 +165,67 @@ impl Window {
     split: HorizontalSplitKind,
     region_index: usize,
   ) -> (usize, usize) {
-    self.layout.horizontal_split(split, region_index)
+    self.layout_mut().horizontal_split(split, region_index)
+  }
+
+  // splits a region into one region per constraint, returning their indices
+  // in order -- the first keeps the index of the region
+  pub fn split(
+    &mut self,
+    direction: Direction,
+    constraints: &[Constraint],
+    region_index: usize,
+  ) -> Vec<usize> {
+    self
+      .layout_mut()
+      .split(direction, constraints, region_index)
+  }
+
+  pub fn inset(&mut self, margin: Margin, region_index: usize) {
+    self.layout_mut().inset(margin, region_index);
+  }
+
+  // shrinks a region to at most a maximum size, keeping it centered
+  pub fn center(&mut self, max_width: u16, max_height: u16, region_index: usize) {
+    self
+      .layout_mut()
+      .center(max_width, max_height, region_index);
+  }
+
+  // shrinks a region by a cell on each side and has the border drawn around
+  // it, which stays around the region as the window is resized
+  pub fn border(&mut self, border: Border, region_index: usize) {
+    self.layout_mut().border(border, region_index);
+  }
+
+  // changes how the border around a region looks, like its title or color
+  pub fn set_border(&mut self, border: Border, region_index: usize) {
+    self.layout_mut().set_border(border, region_index);
+  }
+
+  // merges a region into its sibling, which is cleared to be redrawn
+  pub fn remove_region(&mut self, region_index: usize) {
+    for i in self.layout_mut().remove(region_index) {
+      self.clear_region(i);
+    }
+  }
+
+  // swaps a region, along with everything split off from it, for regions
+  // built from a description, returning their indices in order
+  pub fn replace_region(&mut self, region_index: usize, spec: &SplitSpec) -> Vec<usize> {
+    let region_indices = self.layout_mut().replace(region_index, spec);
+    for &i in region_indices.iter() {
+      self.clear_region(i);
+    }
+    region_indices
+  }
+
+  // replaces the whole layout with regions built from a description,
+  // returning their indices in order
+  pub fn rebuild_layout(&mut self, spec: &SplitSpec) -> Vec<usize> {
+    let region_indices = self.layout_mut().rebuild(spec);
+    self.clear();
+    region_indices
   }
 
   pub fn resize(&mut self, new_width: u16, new_height: u16) {
@@ -121,22 +234,43 @@ impl Window {
       width: new_width,
       height: new_height,
     };
-    self.layout.resize(new_bounds);
+    for layout in self.layouts.iter_mut() {
+      layout.resize(new_bounds);
+    }
 
     self.bounds = new_bounds;
     self.buffer = vec![vec![Cell::new(); new_width as usize]; new_height as usize];
     self.dirty = vec![vec![true; new_width as usize]; new_height as usize];
   }
 
+  // starts a new layout covering the entire window, keeping the current one
+  // to return to with pop_layout
+  pub fn push_layout(&mut self) {
+    self.layouts.push(Layout::new(self.bounds));
+    self.clear();
+  }
+
+  pub fn pop_layout(&mut self) {
+    assert!(
+      self.layouts.len() > 1,
+      "ERROR: Failed to pop layout -- only one layout left."
+    );
+    self.layouts.pop();
+    self.clear();
+  }
+
+  // blanks out the entire window, including anything between regions
   pub fn clear(&mut self) {
-    for i in 0..self.layout.regions().len() {
-      self.clear_region(i);
+    for row in 0..self.bounds.height {
+      for col in 0..self.bounds.width {
+        self.set_cell(row, col, Cell::new());
+      }
     }
   }
 
   pub fn clear_region(&mut self, region_index: usize) {
     let region_bounds = self
-      .layout
+      .layout()
       .region(region_index)
       .expect("ERROR: Failed to clear region -- invalid region index.");
     let clear_text = " ".repeat(region_bounds.width as usize);
@@ -151,15 +285,59 @@ impl Window {
     }
   }
 
+  pub fn size(&self) -> (u16, u16) {
+    (self.bounds.width, self.bounds.height)
+  }
+
   pub fn region(&self, region_index: usize) -> Option<&Rect> {
-    self.layout.region(region_index)
+    self.layout().region(region_index)
   }
 
-  fn check_coord(&self, region_row: u16, region_column: u16, region_index: usize) -> bool {
-    let region_bounds = self
-      .layout
-      .region(region_index)
-      .expect("ERROR: Invalid region index.");
+  // the characters in the window, row by row, for checking what views draw
+  #[cfg(test)]
+  pub fn snapshot(&self) -> Vec<String> {
+    self
+      .buffer
+      .iter()
+      .map(|row| row.iter().map(|cell| cell.c.as_str()).collect())
+      .collect()
+  }
+
+  fn layout(&self) -> &Layout {
+    self.layouts.last().expect("ERROR: Window has no layout.")
+  }
+
+  fn layout_mut(&mut self) -> &mut Layout {
+    self
+      .layouts
+      .last_mut()
+      .expect("ERROR: Window has no layout.")
+  }
+
+  fn set_cell(&mut self, row: u16, col: u16, new_cell: Cell) {
+    let (row, col) = (row as usize, col as usize);
+    if self.buffer[row][col] == new_cell {
+      return;
+    }
+
+    // overwriting half of a wide character blanks out its other half
+    if self.buffer[row][col].c.is_empty() && !new_cell.c.is_empty() && col > 0 {
+      self.buffer[row][col - 1].c = " ".to_owned();
+      self.dirty[row][col - 1] = true;
+    }
+    if self.buffer[row][col].c.width() == 2 && col + 1 < self.buffer[row].len() {
+      let next = &mut self.buffer[row][col + 1];
+      if next.c.is_empty() {
+        next.c = " ".to_owned();
+        self.dirty[row][col + 1] = true;
+      }
+    }
+
+    self.buffer[row][col] = new_cell;
+    self.dirty[row][col] = true;
+  }
+
+  fn check_coord(&self, region_row: u16, region_column: u16, region_bounds: Rect) -> bool {
     let window_row = region_row + region_bounds.coord.row;
     let window_column = region_column + region_bounds.coord.col;
 
@@ -169,10 +347,52 @@ impl Window {
     inside_window && inside_region
   }
 
+  // borders are outside of every region, so they're drawn by the window
+  // rather than by views
+  fn draw_borders(&mut self) {
+    let borders: Vec<(Rect, Border)> = self
+      .layout()
+      .borders()
+      .into_iter()
+      .map(|(bounds, border)| (bounds, border.clone()))
+      .collect();
+    for (bounds, border) in borders {
+      if bounds.width < 2 || bounds.height < 2 {
+        continue;
+      }
+      let chars = border.style.chars();
+      let width = bounds.width as usize;
+      let top = border::top_edge(chars, width, border.title.as_deref());
+      let bottom = border::bottom_edge(chars, width);
+      for (row, edge) in [(0, top), (bounds.height - 1, bottom)] {
+        self.draw_within(
+          &edge,
+          border.fg,
+          Color::Reset,
+          Coord { row, col: 0 },
+          bounds,
+        );
+      }
+      for row in 1..bounds.height - 1 {
+        for col in [0, bounds.width - 1] {
+          self.draw_within(
+            chars.vertical,
+            border.fg,
+            Color::Reset,
+            Coord { row, col },
+            bounds,
+          );
+        }
+      }
+    }
+  }
+
   pub fn display<T: Write>(&mut self, buf: &mut T) {
-    let prev_coord: Option<Coord> = None;
-    let prev_fg: Option<Color> = None;
-    let prev_bg: Option<Color> = None;
+    self.draw_borders();
+
+    let mut prev_coord: Option<Coord> = None;
+    let mut prev_fg: Option<Color> = None;
+    let mut prev_bg: Option<Color> = None;
 
     let handle_error = |res: Result<(), io::Error>| res.expect("ERROR: Failed to display cells.");
 
@@ -183,37 +403,57 @@ impl Window {
           self.dirty[row as usize][col as usize] = false;
 
           let cell = &self.buffer[row as usize][col as usize];
+          let follows_prev =
+            matches!(prev_coord, Some(prev) if prev.row == row && prev.col + 1 == col);
+
+          // the second half of a wide character is printed along with its
+          // first half
+          if cell.c.is_empty() {
+            if follows_prev {
+              prev_coord = Some(Coord { row, col });
+            }
+            continue;
+          }
 
-          if prev_coord.is_none()
-            || row != prev_coord.unwrap().row
-            || col != prev_coord.unwrap().col + 1
-          {
+          if !follows_prev {
             handle_error(queue!(
               buf,
               cursor::MoveTo(col + self.bounds.coord.col, row + self.bounds.coord.row)
             ));
           }
-          if prev_fg.is_none() || cell.fg != prev_fg.unwrap() {
+          if prev_fg != Some(cell.fg) {
             handle_error(queue!(buf, style::SetForegroundColor(cell.fg)));
           }
-          if prev_bg.is_none() || cell.bg != prev_bg.unwrap() {
+          if prev_bg != Some(cell.bg) {
             handle_error(queue!(buf, style::SetBackgroundColor(cell.bg)));
           }
           handle_error(queue!(buf, style::Print(&cell.c)));
+
+          prev_coord = Some(Coord { row, col });
+          prev_fg = Some(cell.fg);
+          prev_bg = Some(cell.bg);
         }
       }
     }
     buf
       .queue(cursor::MoveTo(self.cursor_pos.col, self.cursor_pos.row))
       .expect("ERROR: Failed to move cursor.");
+    if self.cursor_visible {
+      buf.queue(cursor::Show)
+    } else {
+      buf.queue(cursor::Hide)
+    }
+    .expect("ERROR: Failed to set cursor visibility.");
   }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
   use crossterm::style::Color;
 
   use super::*;
+  use crate::framework::border::BorderStyle;
 
   #[test]
   fn it_draws_within_window() {
@@ -436,4 +676,89 @@ mod tests {
     assert_eq!(window.buffer[2][1].c, " ");
     assert_eq!(window.buffer[2][2].c, " ");
   }
+
+  #[test]
+  fn it_draws_wide_characters() {
+    let mut window = Window::new(4, 1);
+    window.draw(
+      "a日本",
+      Color::Reset,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      0,
+    );
+
+    assert_eq!(window.buffer[0][0].c, "a");
+    assert_eq!(window.buffer[0][1].c, "日");
+    assert_eq!(window.buffer[0][2].c, "");
+    assert_eq!(window.buffer[0][3].c, " ");
+
+    window.draw("b", Color::Reset, Color::Reset, Coord { row: 0, col: 2 }, 0);
+
+    assert_eq!(window.buffer[0][1].c, " ");
+    assert_eq!(window.buffer[0][2].c, "b");
+  }
+
+  #[test]
+  fn it_skips_zero_width_characters() {
+    let mut window = Window::new(4, 1);
+    window.draw(
+      "\u{301}a\u{200b}b",
+      Color::Reset,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      0,
+    );
+
+    assert_eq!(window.snapshot(), vec!["ab  "]);
+
+    // a joined sequence measured wider than a cell pair is blanked out
+    let mut window = Window::new(8, 1);
+    window.draw(
+      "a\u{1f468}\u{200d}\u{1f469}b",
+      Color::Reset,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      0,
+    );
+
+    assert_eq!(window.snapshot(), vec!["a    b  "]);
+  }
+
+  #[test]
+  fn it_pushes_and_pops_layouts() {
+    let mut window = Window::new(4, 2);
+    let (_, bottom) = window.horizontal_split(HorizontalSplitKind::CellsInTop(1), 0);
+
+    window.push_layout();
+    assert!(window.region(bottom).is_none());
+    assert_eq!(window.region(0).unwrap().height, 2);
+
+    window.resize(6, 3);
+    window.pop_layout();
+    assert_eq!(window.region(bottom).unwrap().height, 2);
+    assert_eq!(window.region(bottom).unwrap().width, 6);
+  }
+
+  #[test]
+  fn it_draws_borders() {
+    let mut window = Window::new(8, 4);
+    let (_, bottom) = window.horizontal_split(HorizontalSplitKind::CellsInTop(1), 0);
+    let mut border = Border::new(BorderStyle::Ascii);
+    border.title = Some("Hi".to_owned());
+    window.border(border, bottom);
+    window.draw(
+      "abcdefgh",
+      Color::Reset,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      bottom,
+    );
+    window.display(&mut Vec::new());
+
+    assert_eq!(
+      window.snapshot(),
+      vec!["        ", "+ Hi --+", "|abcdef|", "+------+"]
+    );
+  }
 }
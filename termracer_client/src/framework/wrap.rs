@@ -0,0 +1,221 @@
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+// position of a grapheme within a sequence of words
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub struct Position {
+  pub word: usize,
+  pub grapheme: usize,
+}
+
+// a piece of a word which must be kept on one line
+struct Chunk {
+  start: Position,
+  width: usize,
+}
+
+// wraps words, given as graphemes (including any trailing whitespace), into
+// lines no wider than width
+// greedily, fitting as many words as possible onto each line
+// words too wide to fit on a line of their own are broken between graphemes,
+// but trailing whitespace always stays on the same line as its word
+pub fn wrap(words: &[Vec<&str>], width: usize) -> Vec<Range<Position>> {
+  let chunks = chunk(words, width);
+  let end = Position {
+    word: words.len(),
+    grapheme: 0,
+  };
+  if chunks.is_empty() {
+    return vec![end..end];
+  }
+
+  let breaks = greedy_breaks(&chunks, width);
+
+  // breaks holds the index of the first chunk on each line
+  breaks
+    .iter()
+    .enumerate()
+    .map(|(i, &first)| {
+      let line_end = breaks
+        .get(i + 1)
+        .map_or(end, |&next_first| chunks[next_first].start);
+      chunks[first].start..line_end
+    })
+    .collect()
+}
+
+// wraps plain text into lines no wider than width, for views that just need
+// to lay out a paragraph
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+  let words: Vec<Vec<&str>> = split_words(text)
+    .iter()
+    .map(|word| word.graphemes(true).collect())
+    .collect();
+
+  wrap(&words, width)
+    .into_iter()
+    .map(|line| {
+      let mut s = String::new();
+      for (word_index, word) in words.iter().enumerate() {
+        for (grapheme_index, grapheme) in word.iter().enumerate() {
+          let position = Position {
+            word: word_index,
+            grapheme: grapheme_index,
+          };
+          if line.contains(&position) {
+            s.push_str(grapheme);
+          }
+        }
+      }
+      s.trim_end().to_owned()
+    })
+    .collect()
+}
+
+// splits text into words, each keeping the whitespace that follows it
+fn split_words(text: &str) -> Vec<&str> {
+  let mut words = vec![];
+  let mut start = 0;
+  let mut in_whitespace = false;
+  for (i, c) in text.char_indices() {
+    if c.is_whitespace() {
+      in_whitespace = true;
+    } else if in_whitespace {
+      words.push(&text[start..i]);
+      start = i;
+      in_whitespace = false;
+    }
+  }
+  if start < text.len() {
+    words.push(&text[start..]);
+  }
+  words
+}
+
+fn chunk(words: &[Vec<&str>], width: usize) -> Vec<Chunk> {
+  let mut chunks = vec![];
+  for (word_index, graphemes) in words.iter().enumerate() {
+    let trailing_start = graphemes
+      .iter()
+      .rposition(|g| !g.contains(char::is_whitespace))
+      .map_or(0, |i| i + 1);
+
+    let mut start = 0;
+    let mut chunk_width = 0;
+    for (grapheme_index, grapheme) in graphemes[..trailing_start].iter().enumerate() {
+      if grapheme_index > start && chunk_width + grapheme.width() > width {
+        chunks.push(Chunk {
+          start: Position {
+            word: word_index,
+            grapheme: start,
+          },
+          width: chunk_width,
+        });
+        start = grapheme_index;
+        chunk_width = 0;
+      }
+      chunk_width += grapheme.width();
+    }
+
+    let trailing_width: usize = graphemes[trailing_start..].iter().map(|g| g.width()).sum();
+    if !graphemes.is_empty() {
+      chunks.push(Chunk {
+        start: Position {
+          word: word_index,
+          grapheme: start,
+        },
+        width: chunk_width + trailing_width,
+      });
+    }
+  }
+  chunks
+}
+
+fn greedy_breaks(chunks: &[Chunk], width: usize) -> Vec<usize> {
+  let mut breaks = vec![0];
+  let mut line_width = 0;
+  for (i, chunk) in chunks.iter().enumerate() {
+    if i > *breaks.last().unwrap() && line_width + chunk.width > width {
+      breaks.push(i);
+      line_width = 0;
+    }
+    line_width += chunk.width;
+  }
+  breaks
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn words(text: &str) -> Vec<Vec<&str>> {
+    split_words(text)
+      .iter()
+      .map(|word| word.graphemes(true).collect())
+      .collect()
+  }
+
+  fn position(word: usize, grapheme: usize) -> Position {
+    Position { word, grapheme }
+  }
+
+  #[test]
+  fn it_wraps_greedily() {
+    let lines = wrap(&words("aaa bb cc dddd"), 7);
+
+    assert_eq!(
+      lines,
+      vec![
+        position(0, 0)..position(2, 0),
+        position(2, 0)..position(4, 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn it_wraps_text() {
+    assert_eq!(
+      wrap_text("aaa bb cc ddddd", 7),
+      vec!["aaa bb", "cc", "ddddd"]
+    );
+  }
+
+  #[test]
+  fn it_breaks_long_words() {
+    let lines = wrap(&words("ab cdefgh i"), 4);
+
+    assert_eq!(
+      lines,
+      vec![
+        position(0, 0)..position(1, 0),
+        position(1, 0)..position(1, 4),
+        position(1, 4)..position(3, 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn it_keeps_trailing_whitespace_with_word() {
+    let lines = wrap(&words("abcd ef"), 4);
+
+    assert_eq!(
+      lines,
+      vec![
+        position(0, 0)..position(1, 0),
+        position(1, 0)..position(2, 0),
+      ]
+    );
+  }
+
+  #[test]
+  fn it_uses_display_width() {
+    assert_eq!(wrap_text("日本 語の 文", 7), vec!["日本", "語の 文"]);
+  }
+
+  #[test]
+  fn it_wraps_nothing() {
+    assert_eq!(wrap(&[], 10), vec![position(0, 0)..position(0, 0)]);
+  }
+}
@@ -1 +1,4 @@
+pub mod command;
+pub mod resources;
+pub mod results_screen;
 pub mod solo_game;
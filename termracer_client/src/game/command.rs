@@ -0,0 +1,71 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+// actions on the game itself, as opposed to text being typed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Command {
+  Abort,
+  // start over with new text
+  Restart,
+  // start over with the same text
+  Retry,
+  // pause or resume the timer
+  TogglePause,
+}
+
+impl Command {
+  pub fn from_key_event(event: KeyEvent) -> Option<Self> {
+    match (event.code, event.modifiers) {
+      (KeyCode::Esc, _) => Some(Command::Abort),
+      // raw mode stops ctrl+c from sending an interrupt
+      (KeyCode::Char('c'), KeyModifiers::CONTROL) => Some(Command::Abort),
+      (KeyCode::Tab, _) => Some(Command::Restart),
+      (KeyCode::Char('r'), KeyModifiers::CONTROL) => Some(Command::Retry),
+      (KeyCode::Char('p'), KeyModifiers::CONTROL) => Some(Command::TogglePause),
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyEventKind, KeyEventState};
+
+  use super::*;
+
+  fn create_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+    KeyEvent {
+      code,
+      modifiers,
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    }
+  }
+
+  #[test]
+  fn it_maps_commands() {
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Esc, KeyModifiers::empty())),
+      Some(Command::Abort)
+    );
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+      Some(Command::Abort)
+    );
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Char('c'), KeyModifiers::empty())),
+      None
+    );
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Tab, KeyModifiers::empty())),
+      Some(Command::Restart)
+    );
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Char('r'), KeyModifiers::CONTROL)),
+      Some(Command::Retry)
+    );
+    assert_eq!(
+      Command::from_key_event(create_key_event(KeyCode::Char('p'), KeyModifiers::CONTROL)),
+      Some(Command::TogglePause)
+    );
+  }
+}
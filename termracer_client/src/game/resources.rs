@@ -0,0 +1,19 @@
+use crate::models::game_settings::GameSettings;
+use crate::util::keyboard_layouts::KeyboardLayout;
+use crate::util::word_lists::WordList;
+
+// files a test is set up from, loaded before the test starts so that any
+// problems with them can be shown by whatever is starting it
+pub struct Resources {
+  pub word_list: WordList,
+  pub layout: KeyboardLayout,
+}
+
+impl Resources {
+  pub fn load(settings: &GameSettings) -> Result<Self, String> {
+    Ok(Resources {
+      word_list: WordList::load(&settings.word_list)?,
+      layout: KeyboardLayout::load(&settings.layout)?,
+    })
+  }
+}
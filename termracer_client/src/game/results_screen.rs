@@ -0,0 +1,105 @@
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::resources::Resources;
+use super::solo_game::SoloGame;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::models::game_result::GameResult;
+use crate::util::history;
+use crate::views::page::Page;
+use crate::views::text_block::TextBlock;
+use crate::views::view::View;
+
+const TITLE: &str = "Results";
+const HINT: &str = "Enter: next test  Esc: back to menu";
+
+const MIN_WIDTH: u16 = 30;
+const MIN_HEIGHT: u16 = 8;
+
+// the outcome of the last test, which is recorded in the history
+pub struct ResultsScreen {
+  page: Page<TextBlock>,
+  summary: Vec<String>,
+}
+
+impl ResultsScreen {
+  pub fn new(ctx: &mut Context) -> Self {
+    let palette = ctx.settings.theme.palette();
+    let mut page = Page::new(&mut ctx.window, 0, TITLE, HINT, |region_index| {
+      let mut text = TextBlock::new(region_index);
+      text.set_color(palette.text);
+      text
+    });
+    page.set_style(ctx.settings.border_style);
+    page.set_color(palette.highlight);
+
+    let mut summary = match &ctx.last_result {
+      Some(GameResult::Completed { stats }) => vec![
+        "Completed".to_owned(),
+        String::new(),
+        format!("WPM:       {}", stats.wpm as u32),
+        format!("Accuracy:  {:.1}%", stats.accuracy),
+      ],
+      Some(GameResult::Failed { stats, reason }) => vec![
+        format!("Failed: {}", reason),
+        String::new(),
+        format!("WPM:       {}", stats.wpm as u32),
+        format!("Accuracy:  {:.1}%", stats.accuracy),
+      ],
+      Some(GameResult::Aborted) | None => vec!["Aborted".to_owned()],
+    };
+    if let Some(result) = &ctx.last_result {
+      if let Err(error) = history::record(&ctx.settings, result) {
+        summary.push(String::new());
+        summary.push(format!("Failed to save history: {}", error));
+      }
+    }
+
+    let mut screen = ResultsScreen { page, summary };
+    screen.update_text(None);
+    screen
+  }
+
+  fn update_text(&mut self, error: Option<String>) {
+    let mut lines = self.summary.clone();
+    if let Some(error) = error {
+      lines.push(String::new());
+      lines.push(error);
+    }
+    self.page.content.inner.set_lines(lines);
+  }
+}
+
+impl Screen for ResultsScreen {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
+
+  fn handle_key_event(
+    &mut self,
+    ctx: &mut Context,
+    event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    match (event.code, event.modifiers) {
+      (KeyCode::Enter, _) => match Resources::load(&ctx.settings) {
+        Ok(resources) => Some(Transition::replace(|ctx| {
+          SoloGame::new(ctx, resources, true)
+        })),
+        Err(error) => {
+          self.update_text(Some(error));
+          Some(Transition::Stay)
+        }
+      },
+      (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => Some(Transition::Pop),
+      _ => None,
+    }
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    self.page.draw(&mut ctx.window);
+    ctx.window.set_cursor_visible(false);
+  }
+}
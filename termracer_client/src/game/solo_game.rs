@@ -1,131 +1,406 @@
-use std::cmp;
-use std::io::Write;
 use std::time::{Duration, Instant};
 
-use crossterm::event::{self, Event, KeyCode};
-use crossterm::{execute, terminal};
-use termracer_word_generator::word_generator;
-use unicode_segmentation::UnicodeSegmentation;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
 
-use crate::framework::split::HorizontalSplitKind;
-use crate::framework::window::Window;
-use crate::models::game_result::GameResult;
-use crate::util::throttler::Throttler;
+use super::command::Command;
+use super::resources::Resources;
+use super::results_screen::ResultsScreen;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::framework::constraint::{Constraint, Direction};
+use crate::framework::frame_scheduler::RefreshTimer;
+use crate::framework::rect::Margin;
+use crate::models::game_result::{GameResult, Sample, TestStats};
+use crate::models::game_settings::GameSettings;
+use crate::models::mode::Mode;
+use crate::models::progress::Progress;
+use crate::util::keyboard_layouts::KeyboardLayout;
+use crate::util::stopwatch::Stopwatch;
+use crate::util::word_lists::{self, WordList};
 use crate::views::line_block::LineBlock;
+use crate::views::overlay::Overlay;
 use crate::views::progress_bar::ProgressBar;
 use crate::views::stats_line::StatsLine;
 use crate::views::view::{KeyEventHandleable, View};
 
 const AVERAGE_WORD_LENGTH: usize = 5;
 
+// words generated per second of a timed test, enough that nobody runs out
+const TIMED_WORDS_PER_SECOND: usize = 5;
+
+// largest area the text is shown in, including its margin
+const MAX_TEXT_WIDTH: u16 = 84;
+const MAX_TEXT_HEIGHT: u16 = 12;
+
+// room for a few lines of text and the stats line
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 8;
+
+// time without typing after which the test counts as abandoned and the timer
+// pauses until typing resumes
+const IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+const START_PROMPT: &str = "Start typing to begin";
+const IDLE_PROMPT: &str = "Timer paused -- keep typing to resume";
+const PAUSED_MESSAGE: &str = "Paused -- press Ctrl+P to resume";
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum PauseReason {
+  Requested,
+  // paused automatically when switching away from the terminal, and resumed
+  // when switching back
+  FocusLost,
+  // paused while the terminal is too small to show the game
+  Hidden,
+}
+
 struct UI {
-  window: Window,
   // views
   line_block: LineBlock,
   stats_line: StatsLine,
   progress_bar: ProgressBar,
 }
 
+// when each view was last drawn, so that it's only drawn as often as it asks
+#[derive(Default)]
+struct RefreshTimers {
+  line_block: RefreshTimer,
+  stats_line: RefreshTimer,
+  progress_bar: RefreshTimer,
+}
+
 pub struct SoloGame {
+  settings: GameSettings,
+  word_list: WordList,
+  layout: KeyboardLayout,
+  // text of the current test and the seed it was generated from, kept so it
+  // can be retried
+  words: Vec<String>,
+  seed: u64,
+  // whether the results screen replaces the game when it ends, rather than
+  // going back to the screen underneath
+  show_results: bool,
+  // the timer doesn't start until the first character is typed so that
+  // reading the text beforehand doesn't count
+  stopwatch: Stopwatch,
+  last_keystroke: Instant,
+  pause: Option<PauseReason>,
+  samples: Vec<Sample>,
   ui: UI,
+  refresh: RefreshTimers,
 }
 
 impl SoloGame {
-  pub fn new(word_count: usize) -> Self {
-    let (term_width, term_height) = terminal::size().expect("ERROR: Failed to get terminal size.");
-
-    // line block takes up the entire terminal width
-    // scale down to give breathing room
-    // add 1 to account for whitespace
-    let words_per_line = ((term_width / (AVERAGE_WORD_LENGTH + 1) as u16) as f32 * 0.6) as usize;
-    let all_words = word_generator::generate_words(word_count);
-    let text_lines: Vec<Vec<String>> = (0..word_count)
-      .step_by(words_per_line)
-      .map(|i| {
-        all_words[i..cmp::min(i + words_per_line, word_count)]
-          .join(" ")
-          .graphemes(true)
-          .map(String::from)
-          .collect()
-      })
-      .collect();
-
-    let mut window = Window::new(term_width, term_height);
-    let (line_block_region, bottom_region) =
-      window.horizontal_split(HorizontalSplitKind::CellsInBottom(2), 0);
-    let (stats_line_region, progress_bar_region) =
-      window.horizontal_split(HorizontalSplitKind::CellsInBottom(1), bottom_region);
-
-    let line_block = LineBlock::new(text_lines, line_block_region);
-    let stats_line = StatsLine::new(stats_line_region);
-    let progress_bar = ProgressBar::new(progress_bar_region);
+  pub fn new(ctx: &mut Context, resources: Resources, show_results: bool) -> Self {
+    let Resources { word_list, layout } = resources;
+    let settings = ctx.settings.clone();
+    let seed = settings.seed.unwrap_or_else(word_lists::random_seed);
+    let words = word_list.generate(word_count(&settings), seed);
+    let palette = settings.theme.palette();
+
+    let window = &mut ctx.window;
+    let regions = window.split(
+      Direction::Vertical,
+      &[
+        Constraint::Min(1),
+        Constraint::Fixed(1),
+        Constraint::Fixed(1),
+      ],
+      0,
+    );
+    let [line_block_region, stats_line_region, progress_bar_region] = regions[..] else {
+      unreachable!("ERROR: Failed to lay out game -- wrong number of regions.");
+    };
+    // the text is kept in a comfortably sized area in the middle
+    window.center(MAX_TEXT_WIDTH, MAX_TEXT_HEIGHT, line_block_region);
+    window.inset(Margin::symmetric(1, 2), line_block_region);
+
+    let mut line_block = LineBlock::new(
+      words.clone(),
+      line_block_region,
+      settings.input_mode,
+      settings.correction_policy.policy(),
+      palette,
+    );
+    line_block.reflow(window);
+    let stats_line = StatsLine::new(stats_line_region, palette);
+    let progress_bar = ProgressBar::new(progress_bar_region, palette);
 
     SoloGame {
+      settings,
+      word_list,
+      layout,
+      words,
+      seed,
+      show_results,
+      stopwatch: Stopwatch::new(),
+      last_keystroke: Instant::now(),
+      pause: None,
+      samples: vec![],
       ui: UI {
-        window,
         line_block,
         stats_line,
         progress_bar,
       },
+      refresh: RefreshTimers::default(),
     }
   }
 
-  pub fn run<T: Write>(&mut self, buf: &mut T, poll_duration: Duration) -> GameResult {
-    execute!(buf, terminal::EnterAlternateScreen)
-      .expect("ERROR: Failed to enter alternate screen.");
-    terminal::enable_raw_mode().expect("ERROR: Failed to enable raw mode.");
-
-    let game_results = self.game_loop(buf, poll_duration);
+  // resets every view to the start of the current text
+  fn restart(&mut self, ctx: &mut Context) {
+    self.ui.line_block.reset(self.words.clone());
+    self.ui.stats_line.reset();
+    self.ui.progress_bar.reset();
+    ctx.window.clear();
+    self.stopwatch = Stopwatch::new();
+    self.pause = None;
+    self.samples.clear();
+    self.refresh = RefreshTimers::default();
+  }
 
-    terminal::disable_raw_mode().expect("ERROR: Failed to disable raw mode.");
-    execute!(buf, terminal::LeaveAlternateScreen)
-      .expect("ERROR: Failed to leave alternate screen.");
+  fn pause(&mut self, reason: PauseReason, now: Instant) {
+    self.stopwatch.pause(now);
+    self.pause = Some(reason);
+    // the idle prompt goes away while paused
+    self.refresh.stats_line.invalidate();
+  }
 
-    game_results
+  fn resume(&mut self, ctx: &mut Context, now: Instant) {
+    self.stopwatch.resume(now);
+    self.last_keystroke = now;
+    self.pause = None;
+    // clear the overlay away
+    ctx
+      .window
+      .clear_region(self.ui.line_block.get_region_index());
+    self.refresh.line_block.invalidate();
   }
 
-  fn game_loop<T: Write>(&mut self, buf: &mut T, poll_duration: Duration) -> GameResult {
-    let start_instant = Instant::now();
+  fn finish(&self, ctx: &mut Context, result: GameResult) -> Transition {
+    let aborted = matches!(result, GameResult::Aborted);
+    ctx.last_result = Some(result);
+    if self.show_results && !aborted {
+      Transition::replace(ResultsScreen::new)
+    } else {
+      Transition::Pop
+    }
+  }
+}
 
-    let mut throttler = Throttler::new(20);
+impl Screen for SoloGame {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
 
-    loop {
-      if event::poll(poll_duration).expect("ERROR: Failed to poll event.") {
-        match event::read().expect("ERROR: Failed to read event.") {
-          Event::Key(key_event) => match key_event.code {
-            KeyCode::Esc => return GameResult::Aborted,
-            _ => self.ui.line_block.handle_key_event(key_event),
-          },
-          Event::Resize(width, height) => {
-            self.ui.window.resize(width, height);
-            self.ui.window.clear();
-          }
-          _ => (),
+  fn handle_key_event(
+    &mut self,
+    ctx: &mut Context,
+    event: KeyEvent,
+    time: Instant,
+  ) -> Option<Transition> {
+    match Command::from_key_event(event) {
+      Some(Command::Abort) => Some(self.finish(ctx, GameResult::Aborted)),
+      Some(command @ (Command::Restart | Command::Retry)) => {
+        if command == Command::Restart {
+          self.seed = word_lists::random_seed();
+          self.words = self
+            .word_list
+            .generate(word_count(&self.settings), self.seed);
+        }
+        self.restart(ctx);
+        Some(Transition::Stay)
+      }
+      Some(Command::TogglePause) => {
+        match self.pause {
+          Some(_) => self.resume(ctx, time),
+          None => self.pause(PauseReason::Requested, time),
+        }
+        Some(Transition::Stay)
+      }
+      // typing does nothing while paused
+      None if self.pause.is_some() => Some(Transition::Stay),
+      None => {
+        // timed from when the key was pressed rather than when it's handled
+        let was_running = self.stopwatch.is_running();
+        if self.stopwatch.is_started() {
+          self.stopwatch.resume(time);
+        } else if let KeyCode::Char(_) = event.code {
+          self.stopwatch.start(time);
         }
-      } else {
-        // update state
-        let progress = self.ui.line_block.progress();
-        let wpm = (progress.correct as f32)
-          / (AVERAGE_WORD_LENGTH as f32)
-          / (start_instant.elapsed().as_secs_f32() / 60.0);
-        if self.ui.line_block.done() {
-          return GameResult::Completed { wpm };
+        if self.stopwatch.is_running() != was_running {
+          // the prompt to start or keep typing goes away right away
+          self.refresh.stats_line.invalidate();
         }
-        self.ui.stats_line.set_wpm(wpm);
-        self.ui.progress_bar.set_progress(progress);
-
-        // draw to window
-        self.ui.line_block.draw(&mut self.ui.window);
-        throttler.try_run(|| {
-          self.ui.stats_line.draw(&mut self.ui.window);
-          self.ui.progress_bar.draw(&mut self.ui.window);
-        });
-        self.ui.line_block.reset_cursor(&mut self.ui.window);
-
-        // display window on screen
-        self.ui.window.display(buf);
-        buf.flush().expect("ERROR: Failed to flush buffer.");
+        self.last_keystroke = time;
+        // the keystroke goes on to the line block as it would be typed in the
+        // layout being practiced
+        let event = translate(&self.layout, event);
+        self.ui.line_block.handle_key_event(event);
+        self.refresh.line_block.invalidate();
+        Some(Transition::Stay)
+      }
+    }
+  }
+
+  fn handle_event(&mut self, ctx: &mut Context, event: &Event) -> Transition {
+    match event {
+      Event::FocusLost if self.pause.is_none() => {
+        self.pause(PauseReason::FocusLost, Instant::now())
+      }
+      Event::FocusGained if self.pause == Some(PauseReason::FocusLost) => {
+        self.resume(ctx, Instant::now())
+      }
+      _ => (),
+    }
+    Transition::Stay
+  }
+
+  fn on_resize(&mut self, ctx: &mut Context) {
+    self.ui.line_block.reflow(&ctx.window);
+    // the window is cleared when it's resized
+    self.refresh = RefreshTimers::default();
+  }
+
+  fn on_hidden(&mut self, _ctx: &mut Context) {
+    if self.pause.is_none() {
+      self.pause(PauseReason::Hidden, Instant::now());
+    }
+  }
+
+  fn on_shown(&mut self, ctx: &mut Context) {
+    if self.pause == Some(PauseReason::Hidden) {
+      self.resume(ctx, Instant::now());
+    }
+  }
+
+  fn update(&mut self, ctx: &mut Context) -> Transition {
+    let now = Instant::now();
+    if self.stopwatch.is_running() && now.duration_since(self.last_keystroke) >= IDLE_TIMEOUT {
+      // the time spent idle isn't counted
+      self.stopwatch.pause(self.last_keystroke);
+      self.refresh.stats_line.invalidate();
+    }
+    let prompt = if !self.stopwatch.is_started() {
+      Some(START_PROMPT.to_owned())
+    } else if self.pause.is_none() && !self.stopwatch.is_running() {
+      Some(IDLE_PROMPT.to_owned())
+    } else {
+      None
+    };
+
+    let progress = self.ui.line_block.progress();
+    // a finished text is timed up to the keystroke that finished it, not the
+    // frame that noticed
+    let end = if self.ui.line_block.done() {
+      self.last_keystroke
+    } else {
+      now
+    };
+    let elapsed = self.stopwatch.elapsed(end);
+    let (wpm, raw_wpm) = speeds(&progress, elapsed);
+    // seconds the frames skipped over get the speed they end up at
+    while (self.samples.len() as u64) < elapsed.as_secs() {
+      self.samples.push(Sample {
+        second: self.samples.len() as u64 + 1,
+        wpm,
+        raw_wpm,
+      });
+    }
+    let accuracy = self.ui.line_block.accuracy();
+    let time_left = match self.settings.mode {
+      Mode::Words => None,
+      Mode::Time => Some((self.settings.length as u64).saturating_sub(elapsed.as_secs())),
+    };
+    let done = self.ui.line_block.done() || time_left == Some(0);
+    let stats = || TestStats {
+      wpm,
+      raw_wpm,
+      accuracy,
+      duration: elapsed,
+      seed: self.seed,
+      samples: self.samples.clone(),
+    };
+    let line_block = &self.ui.line_block;
+    if let Some(reason) = self.settings.challenge.failure(
+      line_block.has_uncorrected_mistake(),
+      accuracy,
+      line_block.keystrokes(),
+      done,
+    ) {
+      let result = GameResult::Failed {
+        stats: stats(),
+        reason,
+      };
+      return self.finish(ctx, result);
+    }
+    if done {
+      let result = GameResult::Completed { stats: stats() };
+      return self.finish(ctx, result);
+    }
+    self.ui.stats_line.set_wpm(wpm);
+    self.ui.stats_line.set_accuracy(accuracy);
+    self.ui.stats_line.set_time_left(time_left);
+    self.ui.stats_line.set_prompt(prompt);
+    self.ui.progress_bar.set_progress(progress);
+    Transition::Stay
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    let window = &mut ctx.window;
+    let now = Instant::now();
+    // hide the text while paused
+    if self.pause.is_some() {
+      Overlay::new(self.ui.line_block.get_region_index(), PAUSED_MESSAGE).draw(window);
+    } else {
+      self
+        .refresh
+        .line_block
+        .try_draw(&self.ui.line_block, window, now);
+    }
+    let refresh = &mut self.refresh;
+    refresh
+      .stats_line
+      .try_draw(&self.ui.stats_line, window, now);
+    refresh
+      .progress_bar
+      .try_draw(&self.ui.progress_bar, window, now);
+    self.ui.line_block.reset_cursor(window);
+    window.set_cursor_visible(self.pause.is_none());
+  }
+}
+
+// shortcuts are left alone so that they stay where they're expected
+fn translate(layout: &KeyboardLayout, event: KeyEvent) -> KeyEvent {
+  match event.code {
+    KeyCode::Char(c)
+      if !event
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+    {
+      KeyEvent {
+        code: KeyCode::Char(layout.translate(c)),
+        ..event
       }
     }
+    _ => event,
+  }
+}
+
+// words per minute counting correct graphemes, and counting every grapheme
+// typed
+fn speeds(progress: &Progress, elapsed: Duration) -> (f32, f32) {
+  let minutes = elapsed.as_secs_f32() / 60.0;
+  if minutes == 0.0 {
+    return (0.0, 0.0);
+  }
+  let typed = progress.correct + progress.incorrect + progress.extra;
+  let wpm = |graphemes: usize| (graphemes as f32) / (AVERAGE_WORD_LENGTH as f32) / minutes;
+  (wpm(progress.correct), wpm(typed))
+}
+
+fn word_count(settings: &GameSettings) -> usize {
+  match settings.mode {
+    Mode::Words => settings.length,
+    Mode::Time => settings.length * TIMED_WORDS_PER_SECOND,
   }
 }
@@ -1,34 +1,163 @@
+use std::fs;
 use std::io;
-use std::time::Duration;
+use std::process;
 
 use clap::Parser;
 use crossterm::{execute, style};
-use util::cli::{Cli, Commands};
+use util::cli::{Cli, Commands, ExportArgs, HistoryCommands, ImportArgs};
 
+use crate::app::context::Context;
+use crate::app::screen_stack::ScreenStack;
+use crate::game::resources::Resources;
 use crate::game::solo_game::SoloGame;
+use crate::menu::main_menu::MainMenu;
+use crate::menu::stats_screen::StatsScreen;
 use crate::models::game_result::GameResult;
+use crate::util::config::{self, Config};
+use crate::util::report::Report;
+use crate::util::{history, history_formats};
 
+mod app;
 mod framework;
 mod game;
+mod menu;
 mod models;
 mod util;
 mod views;
 
 fn main() {
   let cli = Cli::parse();
+  let config = Config::load(cli.config.as_deref()).unwrap_or_else(|error| exit_with_error(&error));
+  let fps = cli.fps(&config);
 
   match cli.command {
-    Commands::Solo { word_count } => {
+    None => {
+      let mut buf = io::stdout().lock();
+      let mut screens = ScreenStack::new(Context::new(config.settings()));
+      let timing = screens.run(&mut buf, fps, MainMenu::new);
+      if cli.frame_timing {
+        eprintln!("{}", timing);
+      }
+    }
+    Some(Commands::Solo(args)) => {
+      let settings = args.settings(&config);
+      let resources = Resources::load(&settings).unwrap_or_else(|error| exit_with_error(&error));
+
       let mut buf = io::stdout().lock();
 
-      let mut game = SoloGame::new(word_count);
-      let game_results = game.run(&mut buf, Duration::from_millis(1000 / 30));
+      // the game is the only screen, so it ends the session when it's done
+      let mut screens = ScreenStack::new(Context::new(settings.clone()));
+      let timing = screens.run(&mut buf, fps, |ctx| SoloGame::new(ctx, resources, false));
+      if cli.frame_timing {
+        eprintln!("{}", timing);
+      }
+      let game_results = screens
+        .into_context()
+        .last_result
+        .unwrap_or(GameResult::Aborted);
+      if let Err(error) = history::record(&settings, &game_results) {
+        eprintln!("ERROR: Failed to save history: {}", error);
+      }
+
+      // results for scripts go to the file if there is one, or else take the
+      // place of the text for people
+      let mut quiet = args.quiet;
+      if let Some(format) = args.output_format() {
+        let report = Report::new(&settings, &game_results).format(format);
+        match &args.output_file {
+          Some(path) => fs::write(path, report).unwrap_or_else(|error| {
+            exit_with_error(&format!("can't write `{}`: {}", path.display(), error))
+          }),
+          None => {
+            quiet = true;
+            execute!(buf, style::Print(report)).expect("ERROR: Failed to print results.");
+          }
+        }
+      }
+      if !quiet {
+        let end_text = match game_results {
+          GameResult::Completed { stats } => {
+            format!(
+              "WPM: {}\nAccuracy: {:.1}%\n",
+              stats.wpm as u32, stats.accuracy
+            )
+          }
+          GameResult::Failed { reason, .. } => format!("Failed: {}\n", reason),
+          GameResult::Aborted => "Aborted!\n".to_string(),
+        };
+        execute!(buf, style::Print(end_text)).expect("ERROR: Failed to print end text.");
+      }
+    }
+    Some(Commands::Config) => {
+      if let Err(error) = config.validate() {
+        exit_with_error(&error);
+      }
+      match cli.config.or_else(config::default_path) {
+        Some(path) if path.exists() => println!("# {}", path.display()),
+        _ => println!("# no config file, showing defaults"),
+      }
+      let mut effective = config.effective();
+      effective.fps = Some(fps);
+      print!("{}", effective.to_toml());
+    }
+    Some(Commands::Stats) => {
+      let mut buf = io::stdout().lock();
+      let mut screens = ScreenStack::new(Context::new(config.settings()));
+      let timing = screens.run(&mut buf, fps, StatsScreen::new);
+      if cli.frame_timing {
+        eprintln!("{}", timing);
+      }
+    }
+    Some(Commands::History(HistoryCommands::Export(args))) => {
+      export_history(args).unwrap_or_else(|error| exit_with_error(&error))
+    }
+    Some(Commands::History(HistoryCommands::Import(args))) => {
+      import_history(args).unwrap_or_else(|error| exit_with_error(&error))
+    }
+  }
+}
 
-      let end_text = match game_results {
-        GameResult::Completed { wpm } => format!("WPM: {}\n", wpm as u32),
-        GameResult::Aborted => "Aborted!\n".to_string(),
-      };
-      execute!(buf, style::Print(end_text)).expect("ERROR: Failed to print end text.");
+fn export_history(args: ExportArgs) -> Result<(), String> {
+  let filter = args.filter.filter();
+  let mut entries = history::load().map_err(|error| format!("can't load history: {}", error))?;
+  entries.retain(|entry| filter.matches(entry));
+  let text = history_formats::export(&entries, args.format);
+  match args.output_file {
+    Some(path) => {
+      fs::write(&path, text).map_err(|error| format!("can't write `{}`: {}", path.display(), error))
+    }
+    None => {
+      print!("{}", text);
+      Ok(())
     }
   }
 }
+
+fn import_history(args: ImportArgs) -> Result<(), String> {
+  let path = &args.file;
+  let text = fs::read_to_string(path)
+    .map_err(|error| format!("can't read `{}`: {}", path.display(), error))?;
+  let mut imported = history_formats::import(&text, args.format())
+    .map_err(|error| format!("invalid history `{}`: {}", path.display(), error))?;
+  let filter = args.filter.filter();
+  imported.retain(|entry| filter.matches(entry));
+
+  let history_path = history::history_path().map_err(|error| error.to_string())?;
+  let mut entries =
+    history::load_from(&history_path).map_err(|error| format!("can't load history: {}", error))?;
+  let count = imported.len();
+  let added = history::merge(&mut entries, imported);
+  history::save(&history_path, &entries)
+    .map_err(|error| format!("can't save history: {}", error))?;
+  println!(
+    "Imported {} tests, skipping {} already in the history",
+    added,
+    count - added
+  );
+  Ok(())
+}
+
+fn exit_with_error(error: &str) -> ! {
+  eprintln!("ERROR: {}", error);
+  process::exit(1);
+}
@@ -0,0 +1,8 @@
+pub mod history_screen;
+pub mod main_menu;
+pub mod settings_menu;
+pub mod stats_screen;
+
+mod options;
+
+pub const TITLE: &str = "TermRacer";
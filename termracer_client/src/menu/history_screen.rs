@@ -0,0 +1,141 @@
+use std::time::Instant;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::TITLE;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::models::history_entry::HistoryEntry;
+use crate::util::date::format_date;
+use crate::util::history;
+use crate::views::modal::{Modal, ModalResponse};
+use crate::views::page::Page;
+use crate::views::table::{Alignment, Column, Table};
+use crate::views::view::{KeyEventHandleable, View};
+
+const HINT: &str = "Up/Down: scroll  Esc: back";
+
+// room for the columns up to the accuracy and a few rows
+const MIN_WIDTH: u16 = 60;
+const MIN_HEIGHT: u16 = 6;
+
+// past results, newest first
+pub struct HistoryScreen {
+  page: Page<Table>,
+  // shown when the history couldn't be loaded
+  error: Option<Modal>,
+}
+
+impl HistoryScreen {
+  pub fn new(ctx: &mut Context) -> Self {
+    let palette = ctx.settings.theme.palette();
+    let title = format!("{} - History", TITLE);
+    let mut page = Page::new(&mut ctx.window, 0, &title, HINT, |region_index| {
+      let mut table = Table::new(region_index, history_columns());
+      table.set_color(palette.text);
+      table
+    });
+    page.set_style(ctx.settings.border_style);
+    page.set_color(palette.highlight);
+
+    let error = match history::load() {
+      Ok(history) => {
+        page
+          .content
+          .inner
+          .set_rows(history.iter().rev().map(history_row).collect());
+        None
+      }
+      Err(error) => {
+        let message = format!("Failed to load history: {}", error);
+        let mut modal = Modal::new(page.get_region_index(), "Error", &message, &["OK"]);
+        modal.set_style(ctx.settings.border_style);
+        modal.set_color(palette.incorrect);
+        Some(modal)
+      }
+    };
+
+    HistoryScreen { page, error }
+  }
+}
+
+impl Screen for HistoryScreen {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
+
+  fn handle_key_event(
+    &mut self,
+    _ctx: &mut Context,
+    event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    if self.error.is_some() {
+      return None;
+    }
+    match (event.code, event.modifiers) {
+      (KeyCode::Esc | KeyCode::Enter, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+        Some(Transition::Pop)
+      }
+      _ => None,
+    }
+  }
+
+  fn focused_view(&mut self) -> Option<&mut dyn KeyEventHandleable> {
+    match &mut self.error {
+      Some(modal) => Some(modal),
+      None => Some(&mut self.page),
+    }
+  }
+
+  fn update(&mut self, ctx: &mut Context) -> Transition {
+    match &self.error {
+      Some(modal) if modal.response() != ModalResponse::Pending => {
+        self.error = None;
+        ctx.window.clear();
+      }
+      _ => (),
+    }
+    Transition::Stay
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    self.page.draw(&mut ctx.window);
+    if let Some(modal) = &self.error {
+      modal.draw(&mut ctx.window);
+    }
+    ctx.window.set_cursor_visible(false);
+  }
+}
+
+fn history_columns() -> Vec<Column> {
+  [
+    ("Date", Alignment::Left),
+    ("Mode", Alignment::Left),
+    ("Length", Alignment::Right),
+    ("Word list", Alignment::Left),
+    ("WPM", Alignment::Right),
+    ("Accuracy", Alignment::Right),
+    ("Result", Alignment::Left),
+  ]
+  .into_iter()
+  .map(|(title, alignment)| Column {
+    title: title.to_owned(),
+    alignment,
+  })
+  .collect()
+}
+
+fn history_row(entry: &HistoryEntry) -> Vec<String> {
+  vec![
+    format_date(entry.timestamp),
+    entry.mode.to_string(),
+    entry.length.to_string(),
+    entry.word_list.clone(),
+    (entry.wpm as u32).to_string(),
+    format!("{:.1}%", entry.accuracy),
+    entry.failure.map_or("completed".to_owned(), |reason| {
+      format!("failed: {}", reason)
+    }),
+  ]
+}
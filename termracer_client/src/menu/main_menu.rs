@@ -0,0 +1,253 @@
+use std::time::Instant;
+
+use clap::ValueEnum;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::history_screen::HistoryScreen;
+use super::options::cycle;
+use super::settings_menu::SettingsMenu;
+use super::stats_screen::StatsScreen;
+use super::TITLE;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::game::resources::Resources;
+use crate::game::solo_game::SoloGame;
+use crate::models::game_settings::GameSettings;
+use crate::util::word_lists::{self, WordList};
+use crate::views::menu_list::{MenuItem, MenuList};
+use crate::views::modal::{Modal, ModalResponse};
+use crate::views::page::Page;
+use crate::views::view::{KeyEventHandleable, View};
+
+const HINT: &str = "Up/Down: select  Left/Right: change  Enter: confirm  Esc: quit";
+
+// room for every item and its value inside the border
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 11;
+
+const ITEMS: [Item; 8] = [
+  Item::StartTest,
+  Item::Mode,
+  Item::Length,
+  Item::WordList,
+  Item::Settings,
+  Item::History,
+  Item::Stats,
+  Item::Quit,
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Item {
+  StartTest,
+  Mode,
+  Length,
+  WordList,
+  Settings,
+  History,
+  Stats,
+  Quit,
+}
+
+// what a dialog that's open is for
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Dialog {
+  Error,
+  WordList,
+}
+
+struct UI {
+  // views
+  page: Page<MenuList>,
+  modal: Option<(Modal, Dialog)>,
+}
+
+// lets a test be set up and started, and leads to the other screens, without
+// having to know the command line options
+pub struct MainMenu {
+  word_lists: Vec<String>,
+  ui: UI,
+}
+
+impl MainMenu {
+  pub fn new(ctx: &mut Context) -> Self {
+    let palette = ctx.settings.theme.palette();
+    let mut page = Page::new(&mut ctx.window, 0, TITLE, HINT, |region_index| {
+      MenuList::new(region_index, palette)
+    });
+    page.set_style(ctx.settings.border_style);
+    page.set_color(palette.highlight);
+
+    let mut menu = MainMenu {
+      word_lists: word_lists::available(),
+      ui: UI { page, modal: None },
+    };
+    menu.update_items(&ctx.settings);
+    menu
+  }
+
+  fn show_error(&mut self, ctx: &Context, message: &str) {
+    let mut modal = Modal::new(self.ui.page.get_region_index(), "Error", message, &["OK"]);
+    modal.set_style(ctx.settings.border_style);
+    modal.set_color(ctx.settings.theme.palette().incorrect);
+    self.ui.modal = Some((modal, Dialog::Error));
+  }
+
+  fn start_test(&mut self, ctx: &Context) -> Transition {
+    match Resources::load(&ctx.settings) {
+      Ok(resources) => Transition::push(|ctx| SoloGame::new(ctx, resources, true)),
+      Err(error) => {
+        self.show_error(ctx, &error);
+        Transition::Stay
+      }
+    }
+  }
+
+  fn set_word_list(&mut self, ctx: &mut Context, name: &str) {
+    match WordList::load(name) {
+      Ok(_) => {
+        ctx.settings.word_list = name.to_owned();
+        if !self.word_lists.iter().any(|list| list == name) {
+          self.word_lists.push(name.to_owned());
+        }
+        self.update_items(&ctx.settings);
+      }
+      Err(error) => self.show_error(ctx, &error),
+    }
+  }
+
+  // changes the setting of an item to its next or previous option
+  fn change(&mut self, ctx: &mut Context, item: Item, forward: bool) {
+    let settings = &mut ctx.settings;
+    match item {
+      Item::Mode => {
+        settings.mode = cycle(ValueEnum::value_variants(), &settings.mode, forward);
+        settings.length = settings.mode.default_length();
+      }
+      Item::Length => settings.length = cycle(settings.mode.lengths(), &settings.length, forward),
+      Item::WordList => settings.word_list = cycle(&self.word_lists, &settings.word_list, forward),
+      _ => (),
+    }
+    self.update_items(&ctx.settings);
+  }
+
+  fn update_items(&mut self, settings: &GameSettings) {
+    let menu_items = ITEMS
+      .iter()
+      .map(|&item| {
+        let (label, value) = match item {
+          Item::StartTest => ("Start test", None),
+          Item::Mode => ("Mode", Some(settings.mode.to_string())),
+          Item::Length => ("Length", Some(settings.length.to_string())),
+          Item::WordList => ("Word list", Some(settings.word_list.clone())),
+          Item::Settings => ("Settings", None),
+          Item::History => ("History", None),
+          Item::Stats => ("Stats", None),
+          Item::Quit => ("Quit", None),
+        };
+        MenuItem {
+          label: label.to_owned(),
+          value,
+        }
+      })
+      .collect();
+    self.ui.page.content.inner.set_items(menu_items);
+  }
+}
+
+impl Screen for MainMenu {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
+
+  fn handle_key_event(
+    &mut self,
+    ctx: &mut Context,
+    event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    // an open dialog gets every key
+    if self.ui.modal.is_some() {
+      return None;
+    }
+
+    let selected = ITEMS[self.ui.page.content.inner.selected()];
+    let transition = match (event.code, event.modifiers) {
+      (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => Transition::Quit,
+      (KeyCode::Enter, _) => match selected {
+        Item::StartTest => self.start_test(ctx),
+        Item::Settings => Transition::push(SettingsMenu::new),
+        Item::History => Transition::push(HistoryScreen::new),
+        Item::Stats => Transition::push(StatsScreen::new),
+        Item::Quit => Transition::Quit,
+        Item::WordList => {
+          let mut modal = Modal::prompt(
+            self.ui.page.get_region_index(),
+            "Word list",
+            "Name of a word list, or the path to a file of words:",
+            &ctx.settings.word_list,
+          );
+          modal.set_style(ctx.settings.border_style);
+          modal.set_color(ctx.settings.theme.palette().highlight);
+          self.ui.modal = Some((modal, Dialog::WordList));
+          Transition::Stay
+        }
+        item => {
+          self.change(ctx, item, true);
+          Transition::Stay
+        }
+      },
+      (KeyCode::Left, _) => {
+        self.change(ctx, selected, false);
+        Transition::Stay
+      }
+      (KeyCode::Right, _) => {
+        self.change(ctx, selected, true);
+        Transition::Stay
+      }
+      _ => return None,
+    };
+    Some(transition)
+  }
+
+  fn focused_view(&mut self) -> Option<&mut dyn KeyEventHandleable> {
+    match &mut self.ui.modal {
+      Some((modal, _)) => Some(modal),
+      None => Some(&mut self.ui.page),
+    }
+  }
+
+  fn on_resume(&mut self, ctx: &mut Context) {
+    // the settings menu might have changed the theme or borders
+    let palette = ctx.settings.theme.palette();
+    self.ui.page.content.inner.set_palette(palette);
+    self.ui.page.set_style(ctx.settings.border_style);
+    self.ui.page.set_color(palette.highlight);
+    self.update_items(&ctx.settings);
+  }
+
+  fn update(&mut self, ctx: &mut Context) -> Transition {
+    if let Some((modal, dialog)) = &self.ui.modal {
+      let (response, dialog, value) = (modal.response(), *dialog, modal.input_value());
+      if response != ModalResponse::Pending {
+        self.ui.modal = None;
+        ctx.window.clear();
+      }
+      if let (ModalResponse::Confirmed(0), Dialog::WordList) = (response, dialog) {
+        self.set_word_list(ctx, value.unwrap_or_default().trim());
+      }
+    }
+    Transition::Stay
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    let window = &mut ctx.window;
+    self.ui.page.draw(window);
+    match &self.ui.modal {
+      Some((modal, _)) => {
+        modal.draw(window);
+        modal.reset_cursor(window);
+      }
+      None => window.set_cursor_visible(false),
+    }
+  }
+}
@@ -0,0 +1,34 @@
+// the option after (or before) the current one, wrapping around, or the first
+// option if the current one isn't among them
+pub fn cycle<T: PartialEq + Clone>(options: &[T], current: &T, forward: bool) -> T {
+  let count = options.len();
+  let index = match options.iter().position(|option| option == current) {
+    Some(i) if forward => (i + 1) % count,
+    Some(i) => (i + count - 1) % count,
+    None => 0,
+  };
+  options[index].clone()
+}
+
+pub fn on_off(value: bool) -> &'static str {
+  if value {
+    "on"
+  } else {
+    "off"
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_cycles_options() {
+    let options = [1, 2, 3];
+
+    assert_eq!(cycle(&options, &1, true), 2);
+    assert_eq!(cycle(&options, &3, true), 1);
+    assert_eq!(cycle(&options, &1, false), 3);
+    assert_eq!(cycle(&options, &7, true), 1);
+  }
+}
@@ -0,0 +1,189 @@
+use std::time::Instant;
+
+use clap::ValueEnum;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use super::options::{cycle, on_off};
+use super::TITLE;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::framework::border::BorderStyle;
+use crate::models::correction_policy::CorrectionPolicyKind;
+use crate::models::game_settings::GameSettings;
+use crate::models::input_mode::InputMode;
+use crate::models::theme::Theme;
+use crate::util::keyboard_layouts;
+use crate::views::menu_list::{MenuItem, MenuList};
+use crate::views::page::Page;
+use crate::views::view::{KeyEventHandleable, View};
+
+const HINT: &str = "Up/Down: select  Left/Right: change  Esc: back";
+
+const MIN_ACCURACY_OPTIONS: [Option<f32>; 5] =
+  [None, Some(80.0), Some(90.0), Some(95.0), Some(100.0)];
+
+// room for every item and its value inside the border
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 11;
+
+const ITEMS: [Item; 8] = [
+  Item::Theme,
+  Item::Borders,
+  Item::Layout,
+  Item::InputMode,
+  Item::CorrectionPolicy,
+  Item::SuddenDeath,
+  Item::MinAccuracy,
+  Item::Back,
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Item {
+  Theme,
+  Borders,
+  Layout,
+  InputMode,
+  CorrectionPolicy,
+  SuddenDeath,
+  MinAccuracy,
+  Back,
+}
+
+// settings that are changed less often than the ones on the main menu
+pub struct SettingsMenu {
+  // keyboard layouts to choose from
+  layouts: Vec<String>,
+  page: Page<MenuList>,
+}
+
+impl SettingsMenu {
+  pub fn new(ctx: &mut Context) -> Self {
+    let palette = ctx.settings.theme.palette();
+    let title = format!("{} - Settings", TITLE);
+    let mut page = Page::new(&mut ctx.window, 0, &title, HINT, |region_index| {
+      MenuList::new(region_index, palette)
+    });
+    page.set_style(ctx.settings.border_style);
+    page.set_color(palette.highlight);
+
+    let mut menu = SettingsMenu {
+      layouts: keyboard_layouts::available(),
+      page,
+    };
+    menu.update_items(&ctx.settings);
+    menu
+  }
+
+  // changes the setting of an item to its next or previous option
+  fn change(&mut self, ctx: &mut Context, item: Item, forward: bool) {
+    let settings = &mut ctx.settings;
+    match item {
+      Item::Theme => {
+        settings.theme = cycle(Theme::value_variants(), &settings.theme, forward);
+        let palette = settings.theme.palette();
+        self.page.content.inner.set_palette(palette);
+        self.page.set_color(palette.highlight);
+      }
+      Item::Borders => {
+        settings.border_style = cycle(
+          BorderStyle::value_variants(),
+          &settings.border_style,
+          forward,
+        );
+        self.page.set_style(settings.border_style);
+      }
+      Item::Layout => settings.layout = cycle(&self.layouts, &settings.layout, forward),
+      Item::InputMode => {
+        settings.input_mode = cycle(InputMode::value_variants(), &settings.input_mode, forward)
+      }
+      Item::CorrectionPolicy => {
+        settings.correction_policy = cycle(
+          CorrectionPolicyKind::value_variants(),
+          &settings.correction_policy,
+          forward,
+        )
+      }
+      Item::SuddenDeath => settings.challenge.sudden_death = !settings.challenge.sudden_death,
+      Item::MinAccuracy => {
+        settings.challenge.min_accuracy = cycle(
+          &MIN_ACCURACY_OPTIONS,
+          &settings.challenge.min_accuracy,
+          forward,
+        )
+      }
+      Item::Back => (),
+    }
+    self.update_items(&ctx.settings);
+  }
+
+  fn update_items(&mut self, settings: &GameSettings) {
+    let menu_items = ITEMS
+      .iter()
+      .map(|&item| {
+        let (label, value) = match item {
+          Item::Theme => ("Theme", Some(settings.theme.to_string())),
+          Item::Borders => ("Borders", Some(settings.border_style.to_string())),
+          Item::Layout => ("Keyboard layout", Some(settings.layout.clone())),
+          Item::InputMode => ("Input mode", Some(settings.input_mode.to_string())),
+          Item::CorrectionPolicy => ("Corrections", Some(settings.correction_policy.to_string())),
+          Item::SuddenDeath => (
+            "Sudden death",
+            Some(on_off(settings.challenge.sudden_death).to_owned()),
+          ),
+          Item::MinAccuracy => (
+            "Minimum accuracy",
+            Some(
+              settings
+                .challenge
+                .min_accuracy
+                .map_or("off".to_owned(), |accuracy| format!("{}%", accuracy)),
+            ),
+          ),
+          Item::Back => ("Back", None),
+        };
+        MenuItem {
+          label: label.to_owned(),
+          value,
+        }
+      })
+      .collect();
+    self.page.content.inner.set_items(menu_items);
+  }
+}
+
+impl Screen for SettingsMenu {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
+
+  fn handle_key_event(
+    &mut self,
+    ctx: &mut Context,
+    event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    let selected = ITEMS[self.page.content.inner.selected()];
+    match (event.code, event.modifiers) {
+      (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => Some(Transition::Pop),
+      (KeyCode::Enter, _) if selected == Item::Back => Some(Transition::Pop),
+      (KeyCode::Enter | KeyCode::Right, _) => {
+        self.change(ctx, selected, true);
+        Some(Transition::Stay)
+      }
+      (KeyCode::Left, _) => {
+        self.change(ctx, selected, false);
+        Some(Transition::Stay)
+      }
+      _ => None,
+    }
+  }
+
+  fn focused_view(&mut self) -> Option<&mut dyn KeyEventHandleable> {
+    Some(&mut self.page)
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    self.page.draw(&mut ctx.window);
+    ctx.window.set_cursor_visible(false);
+  }
+}
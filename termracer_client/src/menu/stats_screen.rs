@@ -0,0 +1,295 @@
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+
+use super::TITLE;
+use crate::app::context::Context;
+use crate::app::screen::{Screen, Transition};
+use crate::framework::border::{Border, BorderStyle};
+use crate::framework::constraint::{Constraint, Direction};
+use crate::framework::coord::Coord;
+use crate::framework::split::SplitSpec;
+use crate::models::history_stats::{DayStats, HistoryStats};
+use crate::util::date::{self, format_date, SECONDS_PER_DAY};
+use crate::util::history;
+use crate::views::bar_chart::{Bar, BarChart};
+use crate::views::modal::{Modal, ModalResponse};
+use crate::views::text_block::TextBlock;
+use crate::views::view::{KeyEventHandleable, View};
+
+const HINT: &str = "Esc: back";
+
+// room for the summaries side by side and a few rows of each chart
+const MIN_WIDTH: u16 = 72;
+const MIN_HEIGHT: u16 = 18;
+
+// lines in each summary, inside its border
+const SUMMARY_HEIGHT: u16 = 3;
+
+struct UI {
+  // views
+  averages: TextBlock,
+  bests: TextBlock,
+  practice: TextBlock,
+  // the charts are dropped when there's nothing to chart
+  wpm_chart: Option<BarChart>,
+  accuracy_chart: Option<BarChart>,
+  hint_region: usize,
+  // shown when the history couldn't be loaded
+  error: Option<Modal>,
+}
+
+// how practice is going, worked out from the history
+pub struct StatsScreen {
+  ui: UI,
+}
+
+impl StatsScreen {
+  pub fn new(ctx: &mut Context) -> Self {
+    let palette = ctx.settings.theme.palette();
+    let title = format!("{} - Stats", TITLE);
+    let style = ctx.settings.border_style;
+    let boxed = |title: &str| {
+      SplitSpec::bordered(titled_border(title, style, palette.text), SplitSpec::Region)
+    };
+    let regions = ctx.window.rebuild_layout(&SplitSpec::split(
+      Direction::Vertical,
+      vec![
+        (
+          Constraint::Min(1),
+          SplitSpec::bordered(
+            titled_border(&title, style, palette.highlight),
+            SplitSpec::split(
+              Direction::Vertical,
+              vec![
+                // the summaries take no more room than they need, leaving the
+                // rest to the charts
+                (
+                  Constraint::Max(SUMMARY_HEIGHT + 2),
+                  SplitSpec::split(
+                    Direction::Horizontal,
+                    vec![
+                      (Constraint::Ratio(1, 3), boxed("Averages")),
+                      (Constraint::Ratio(1, 3), boxed("Best")),
+                      (Constraint::Fill(1), boxed("Practice")),
+                    ],
+                  ),
+                ),
+                (
+                  Constraint::Fill(1),
+                  SplitSpec::split(
+                    Direction::Vertical,
+                    vec![
+                      (Constraint::Fill(1), boxed("WPM by day")),
+                      (Constraint::Fill(1), boxed("Accuracy by day")),
+                    ],
+                  ),
+                ),
+              ],
+            ),
+          ),
+        ),
+        (Constraint::Fixed(1), SplitSpec::Region),
+      ],
+    ));
+    let [averages_region, bests_region, practice_region, wpm_region, accuracy_region, hint_region] =
+      regions[..]
+    else {
+      unreachable!("ERROR: Failed to lay out stats -- wrong number of regions.");
+    };
+
+    let text_block = |region_index| {
+      let mut text = TextBlock::new(region_index);
+      text.set_color(palette.text);
+      text
+    };
+    let chart = |region_index, fg| {
+      let mut chart = BarChart::new(region_index);
+      chart.set_color(fg);
+      chart
+    };
+    let mut ui = UI {
+      averages: text_block(averages_region),
+      bests: text_block(bests_region),
+      practice: text_block(practice_region),
+      wpm_chart: Some(chart(wpm_region, palette.correct)),
+      accuracy_chart: Some(chart(accuracy_region, palette.highlight)),
+      hint_region,
+      error: None,
+    };
+
+    match history::load() {
+      Ok(history) => {
+        let today = date::day(date::now());
+        let stats = HistoryStats::new(&history, today);
+        // with nothing to chart, one empty chart says as much as two
+        if stats.days.is_empty() {
+          ctx.window.remove_region(accuracy_region);
+          ui.accuracy_chart = None;
+        }
+        set_stats(&mut ui, &stats, today);
+      }
+      Err(error) => {
+        // the charts make way for the error
+        ctx.window.replace_region(wpm_region, &SplitSpec::Region);
+        ui.wpm_chart = None;
+        ui.accuracy_chart = None;
+        let message = format!("Failed to load history: {}", error);
+        let mut modal = Modal::new(wpm_region, "Error", &message, &["OK"]);
+        modal.set_style(style);
+        modal.set_color(palette.incorrect);
+        ui.error = Some(modal);
+      }
+    }
+
+    StatsScreen { ui }
+  }
+}
+
+impl Screen for StatsScreen {
+  fn min_size(&self) -> (u16, u16) {
+    (MIN_WIDTH, MIN_HEIGHT)
+  }
+
+  fn handle_key_event(
+    &mut self,
+    _ctx: &mut Context,
+    event: KeyEvent,
+    _time: Instant,
+  ) -> Option<Transition> {
+    if self.ui.error.is_some() {
+      return None;
+    }
+    match (event.code, event.modifiers) {
+      (KeyCode::Esc | KeyCode::Enter, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+        Some(Transition::Pop)
+      }
+      _ => None,
+    }
+  }
+
+  fn focused_view(&mut self) -> Option<&mut dyn KeyEventHandleable> {
+    self
+      .ui
+      .error
+      .as_mut()
+      .map(|modal| modal as &mut dyn KeyEventHandleable)
+  }
+
+  fn update(&mut self, ctx: &mut Context) -> Transition {
+    match &self.ui.error {
+      Some(modal) if modal.response() != ModalResponse::Pending => {
+        self.ui.error = None;
+        ctx.window.clear();
+      }
+      _ => (),
+    }
+    Transition::Stay
+  }
+
+  fn draw(&mut self, ctx: &mut Context) {
+    let window = &mut ctx.window;
+    let ui = &self.ui;
+    ui.averages.draw(window);
+    ui.bests.draw(window);
+    ui.practice.draw(window);
+    for chart in ui.wpm_chart.iter().chain(ui.accuracy_chart.iter()) {
+      chart.draw(window);
+    }
+    window.clear_region(ui.hint_region);
+    window.draw(
+      HINT,
+      Color::DarkGrey,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      ui.hint_region,
+    );
+    if let Some(modal) = &ui.error {
+      modal.draw(window);
+    }
+    window.set_cursor_visible(false);
+  }
+}
+
+fn titled_border(title: &str, style: BorderStyle, fg: Color) -> Border {
+  Border {
+    title: Some(title.to_owned()),
+    fg,
+    ..Border::new(style)
+  }
+}
+
+fn set_stats(ui: &mut UI, stats: &HistoryStats, today: u64) {
+  ui.averages.set_lines(
+    stats
+      .rolling
+      .iter()
+      .map(|(count, average)| match average {
+        Some(average) => format!(
+          "Last {:<4} {:>3} wpm {:>5.1}%",
+          count, average.wpm as u32, average.accuracy
+        ),
+        None => format!("Last {:<4} -", count),
+      })
+      .collect(),
+  );
+
+  let bests = if stats.best.is_empty() {
+    vec!["No completed tests".to_owned()]
+  } else {
+    stats
+      .best
+      .iter()
+      .map(|entry| {
+        let test = format!("{} {}", entry.mode, entry.length);
+        format!("{:<9} {:>3} wpm", test, entry.wpm as u32)
+      })
+      .collect()
+  };
+  ui.bests.set_lines(bests);
+
+  let plural = |count: u64| if count == 1 { "" } else { "s" };
+  ui.practice.set_lines(vec![
+    format!("Tests  {}", stats.tests),
+    format!("Time   {}", format_duration(stats.time_practiced)),
+    format!(
+      "Streak {} day{} (best {})",
+      stats.streak,
+      plural(stats.streak),
+      stats.longest_streak
+    ),
+  ]);
+
+  if let Some(chart) = &mut ui.wpm_chart {
+    chart.set_bars(daily_bars(&stats.days, today, |day| day.average.wpm));
+  }
+  if let Some(chart) = &mut ui.accuracy_chart {
+    chart.set_bars(daily_bars(&stats.days, today, |day| day.average.accuracy));
+  }
+}
+
+// a bar for every day from the first with tests up to today, leaving gaps for
+// days without any
+fn daily_bars<F: Fn(&DayStats) -> f32>(days: &[DayStats], today: u64, value: F) -> Vec<Bar> {
+  let (Some(first), Some(last)) = (days.first(), days.last()) else {
+    return vec![];
+  };
+  let end = today.max(last.day);
+  let mut days = days.iter().peekable();
+  (first.day..=end)
+    .map(|day| Bar {
+      label: format_date(day * SECONDS_PER_DAY),
+      value: days.next_if(|stats| stats.day == day).map(&value),
+    })
+    .collect()
+}
+
+fn format_duration(duration: Duration) -> String {
+  let seconds = duration.as_secs();
+  match (seconds / 3600, seconds / 60 % 60) {
+    (0, 0) => format!("{}s", seconds),
+    (0, minutes) => format!("{}m {:02}s", minutes, seconds % 60),
+    (hours, minutes) => format!("{}h {:02}m", hours, minutes),
+  }
+}
@@ -1,2 +1,11 @@
+pub mod challenge;
+pub mod correction_policy;
 pub mod game_result;
+pub mod game_settings;
+pub mod history_entry;
+pub mod history_stats;
+pub mod input_mode;
+pub mod mode;
 pub mod progress;
+pub mod theme;
+pub mod word;
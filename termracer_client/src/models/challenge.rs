@@ -0,0 +1,152 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+// extra conditions under which a run fails
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Challenge {
+  // fail on the first mistake left behind uncorrected
+  pub sudden_death: bool,
+  // fail as soon as accuracy (as a percentage) drops below this
+  pub min_accuracy: Option<f32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+pub enum FailureReason {
+  UncorrectedMistake,
+  LowAccuracy { accuracy: f32, min_accuracy: f32 },
+}
+
+impl FailureReason {
+  // the name the reason goes by in files, the same as its tag in json
+  pub fn id(&self) -> &'static str {
+    match self {
+      FailureReason::UncorrectedMistake => "uncorrected-mistake",
+      FailureReason::LowAccuracy { .. } => "low-accuracy",
+    }
+  }
+}
+
+impl fmt::Display for FailureReason {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self {
+      FailureReason::UncorrectedMistake => write!(f, "left a mistake uncorrected"),
+      FailureReason::LowAccuracy {
+        accuracy,
+        min_accuracy,
+      } => write!(
+        f,
+        "accuracy of {:.1}% was below {:.1}%",
+        accuracy, min_accuracy
+      ),
+    }
+  }
+}
+
+// keystrokes typed before accuracy is checked during a run, so that a single
+// early mistake doesn't end it
+const MIN_KEYSTROKES: usize = 10;
+
+impl Challenge {
+  pub fn failure(
+    &self,
+    uncorrected_mistake: bool,
+    accuracy: f32,
+    keystrokes: usize,
+    done: bool,
+  ) -> Option<FailureReason> {
+    if self.sudden_death && uncorrected_mistake {
+      return Some(FailureReason::UncorrectedMistake);
+    }
+    match self.min_accuracy {
+      Some(min_accuracy) if (done || keystrokes >= MIN_KEYSTROKES) && accuracy < min_accuracy => {
+        Some(FailureReason::LowAccuracy {
+          accuracy,
+          min_accuracy,
+        })
+      }
+      _ => None,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_fails_on_uncorrected_mistakes() {
+    let challenge = Challenge {
+      sudden_death: true,
+      min_accuracy: None,
+    };
+
+    assert_eq!(challenge.failure(false, 50.0, 20, true), None);
+    assert_eq!(
+      challenge.failure(true, 100.0, 1, false),
+      Some(FailureReason::UncorrectedMistake)
+    );
+    assert_eq!(Challenge::default().failure(true, 100.0, 1, false), None);
+  }
+
+  #[test]
+  fn it_fails_on_low_accuracy() {
+    let challenge = Challenge {
+      sudden_death: false,
+      min_accuracy: Some(90.0),
+    };
+
+    assert_eq!(challenge.failure(true, 50.0, 2, false), None);
+    assert_eq!(challenge.failure(false, 95.0, 20, true), None);
+    assert_eq!(
+      challenge.failure(false, 85.0, 20, true),
+      Some(FailureReason::LowAccuracy {
+        accuracy: 85.0,
+        min_accuracy: 90.0
+      })
+    );
+  }
+
+  #[test]
+  fn it_fails_when_accuracy_drops_mid_run() {
+    let challenge = Challenge {
+      sudden_death: false,
+      min_accuracy: Some(90.0),
+    };
+
+    // too few keystrokes to judge yet
+    assert_eq!(challenge.failure(false, 80.0, 5, false), None);
+    assert_eq!(challenge.failure(false, 95.0, 20, false), None);
+    assert_eq!(
+      challenge.failure(false, 80.0, 10, false),
+      Some(FailureReason::LowAccuracy {
+        accuracy: 80.0,
+        min_accuracy: 90.0
+      })
+    );
+    // a short run is still checked once it's done
+    assert_eq!(
+      challenge.failure(false, 80.0, 5, true),
+      Some(FailureReason::LowAccuracy {
+        accuracy: 80.0,
+        min_accuracy: 90.0
+      })
+    );
+  }
+
+  #[test]
+  fn it_names_reasons_like_json() {
+    let reasons = [
+      FailureReason::UncorrectedMistake,
+      FailureReason::LowAccuracy {
+        accuracy: 80.0,
+        min_accuracy: 90.0,
+      },
+    ];
+    for reason in reasons {
+      let json = serde_json::to_value(reason).unwrap();
+      assert_eq!(json["reason"], reason.id());
+    }
+  }
+}
@@ -0,0 +1,92 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+// decides how mistakes can be made and corrected while typing
+pub trait CorrectionPolicy {
+  // whether the cursor moves on after a grapheme is typed incorrectly
+  fn advances_on_error(&self) -> bool {
+    true
+  }
+
+  // whether typed graphemes can be deleted
+  fn allows_backspace(&self) -> bool {
+    true
+  }
+
+  // whether a word containing mistakes can be left by typing a space
+  fn allows_leaving_incorrect_word(&self) -> bool {
+    true
+  }
+
+  // whether every mistake has to be corrected before the text is done
+  fn requires_correction(&self) -> bool {
+    true
+  }
+}
+
+// mistakes can be made freely and corrected at any time
+pub struct Free;
+
+impl CorrectionPolicy for Free {}
+
+// the cursor doesn't advance until the correct key is typed
+pub struct StopOnError;
+
+impl CorrectionPolicy for StopOnError {
+  fn advances_on_error(&self) -> bool {
+    false
+  }
+}
+
+// mistakes are permanent
+pub struct NoBackspace;
+
+impl CorrectionPolicy for NoBackspace {
+  fn allows_backspace(&self) -> bool {
+    false
+  }
+
+  fn requires_correction(&self) -> bool {
+    false
+  }
+}
+
+// each word has to be correct before moving on to the next
+pub struct StopOnWord;
+
+impl CorrectionPolicy for StopOnWord {
+  fn allows_leaving_incorrect_word(&self) -> bool {
+    false
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CorrectionPolicyKind {
+  Free,
+  StopOnError,
+  NoBackspace,
+  StopOnWord,
+}
+
+impl CorrectionPolicyKind {
+  pub fn policy(self) -> Box<dyn CorrectionPolicy> {
+    match self {
+      CorrectionPolicyKind::Free => Box::new(Free),
+      CorrectionPolicyKind::StopOnError => Box::new(StopOnError),
+      CorrectionPolicyKind::NoBackspace => Box::new(NoBackspace),
+      CorrectionPolicyKind::StopOnWord => Box::new(StopOnWord),
+    }
+  }
+}
+
+impl fmt::Display for CorrectionPolicyKind {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = self
+      .to_possible_value()
+      .expect("ERROR: Failed to name correction policy.");
+    write!(f, "{}", name.get_name())
+  }
+}
@@ -1,4 +1,47 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::challenge::FailureReason;
+
+// how fast and accurately a test was typed
+#[derive(Debug, PartialEq, Clone)]
+pub struct TestStats {
+  pub wpm: f32,
+  // speed counting every grapheme typed, mistakes included
+  pub raw_wpm: f32,
+  pub accuracy: f32,
+  // time spent typing, not counting pauses
+  pub duration: Duration,
+  // seed the text was generated from
+  pub seed: u64,
+  // speed at the end of each second of the test
+  pub samples: Vec<Sample>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize)]
+pub struct Sample {
+  pub second: u64,
+  pub wpm: f32,
+  pub raw_wpm: f32,
+}
+
 pub enum GameResult {
-  Completed { wpm: f32 },
+  Completed {
+    stats: TestStats,
+  },
+  Failed {
+    stats: TestStats,
+    reason: FailureReason,
+  },
   Aborted,
 }
+
+impl GameResult {
+  pub fn stats(&self) -> Option<&TestStats> {
+    match self {
+      GameResult::Completed { stats } | GameResult::Failed { stats, .. } => Some(stats),
+      GameResult::Aborted => None,
+    }
+  }
+}
@@ -0,0 +1,45 @@
+use super::challenge::Challenge;
+use super::correction_policy::CorrectionPolicyKind;
+use super::input_mode::InputMode;
+use super::mode::Mode;
+use super::theme::Theme;
+use crate::framework::border::BorderStyle;
+use crate::util::keyboard_layouts::DEFAULT_LAYOUT;
+use crate::util::word_lists::DEFAULT_WORD_LIST;
+
+// everything needed to set up a game
+#[derive(Debug, PartialEq, Clone)]
+pub struct GameSettings {
+  pub mode: Mode,
+  // number of words or seconds, depending on the mode
+  pub length: usize,
+  pub word_list: String,
+  // keyboard layout being practiced, translated from qwerty
+  pub layout: String,
+  pub theme: Theme,
+  // how the boxes around the menus are drawn
+  pub border_style: BorderStyle,
+  pub input_mode: InputMode,
+  pub correction_policy: CorrectionPolicyKind,
+  pub challenge: Challenge,
+  // seed the first test's text is generated from, so that a test can be
+  // repeated -- a random one is picked if there isn't one
+  pub seed: Option<u64>,
+}
+
+impl Default for GameSettings {
+  fn default() -> Self {
+    GameSettings {
+      mode: Mode::Words,
+      length: Mode::Words.default_length(),
+      word_list: DEFAULT_WORD_LIST.to_owned(),
+      layout: DEFAULT_LAYOUT.to_owned(),
+      theme: Theme::Default,
+      border_style: BorderStyle::Single,
+      input_mode: InputMode::Continuous,
+      correction_policy: CorrectionPolicyKind::Free,
+      challenge: Challenge::default(),
+      seed: None,
+    }
+  }
+}
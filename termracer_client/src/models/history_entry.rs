@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::challenge::FailureReason;
+use super::game_result::GameResult;
+use super::game_settings::GameSettings;
+use super::mode::Mode;
+
+// a finished test as it's stored in the history
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+  // seconds since the unix epoch
+  pub timestamp: u64,
+  pub mode: Mode,
+  pub length: usize,
+  pub word_list: String,
+  // seed the text was generated from, which older entries don't have
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub seed: Option<u64>,
+  pub wpm: f32,
+  pub accuracy: f32,
+  // seconds spent typing, which older entries don't have
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub duration: Option<f32>,
+  // set if the test was failed rather than completed
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub failure: Option<FailureReason>,
+}
+
+impl HistoryEntry {
+  // aborted tests aren't recorded
+  pub fn new(settings: &GameSettings, result: &GameResult, timestamp: u64) -> Option<Self> {
+    let (stats, failure) = match result {
+      GameResult::Completed { stats } => (stats, None),
+      GameResult::Failed { stats, reason } => (stats, Some(*reason)),
+      GameResult::Aborted => return None,
+    };
+    Some(HistoryEntry {
+      timestamp,
+      mode: settings.mode,
+      length: settings.length,
+      word_list: settings.word_list.clone(),
+      seed: Some(stats.seed),
+      wpm: stats.wpm,
+      accuracy: stats.accuracy,
+      duration: Some(stats.duration.as_secs_f32()),
+      failure,
+    })
+  }
+
+  // time spent typing, worked out from the length and speed for entries
+  // recorded without it
+  pub fn time_spent(&self) -> Duration {
+    let seconds = match (self.duration, self.mode) {
+      (Some(duration), _) => duration,
+      (None, Mode::Time) => self.length as f32,
+      (None, Mode::Words) if self.wpm > 0.0 => self.length as f32 / self.wpm * 60.0,
+      (None, Mode::Words) => 0.0,
+    };
+    Duration::from_secs_f32(seconds.max(0.0))
+  }
+
+  // entries with the same key are the same test, e.g. when histories from
+  // different machines are merged
+  pub fn key(&self) -> (u64, Option<u64>, Mode) {
+    (self.timestamp, self.seed, self.mode)
+  }
+}
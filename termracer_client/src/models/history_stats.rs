@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use super::history_entry::HistoryEntry;
+use super::mode::Mode;
+use crate::util::date;
+
+// numbers of recent tests that averages are taken over
+pub const ROLLING_COUNTS: [usize; 2] = [10, 100];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Average {
+  pub tests: usize,
+  pub wpm: f32,
+  pub accuracy: f32,
+}
+
+impl Average {
+  // none if there's nothing to average
+  fn of<'a, I: IntoIterator<Item = &'a HistoryEntry>>(entries: I) -> Option<Self> {
+    let (tests, wpm, accuracy) = entries
+      .into_iter()
+      .fold((0, 0.0, 0.0), |(tests, wpm, accuracy), entry| {
+        (tests + 1, wpm + entry.wpm, accuracy + entry.accuracy)
+      });
+    (tests > 0).then(|| Average {
+      tests,
+      wpm: wpm / tests as f32,
+      accuracy: accuracy / tests as f32,
+    })
+  }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DayStats {
+  // days since the unix epoch
+  pub day: u64,
+  pub average: Average,
+}
+
+// how practice is going according to the history -- speeds only count
+// completed tests, but any test counts as practice
+#[derive(Debug, PartialEq, Clone)]
+pub struct HistoryStats {
+  // averages of the latest tests, one for each of the rolling counts
+  pub rolling: Vec<(usize, Option<Average>)>,
+  // the fastest test of each mode that's been completed
+  pub best: Vec<HistoryEntry>,
+  // averages of each day with completed tests, oldest first
+  pub days: Vec<DayStats>,
+  pub tests: usize,
+  pub time_practiced: Duration,
+  // days in a row with a test, which isn't broken until a whole day passes
+  // without one
+  pub streak: u64,
+  pub longest_streak: u64,
+}
+
+impl HistoryStats {
+  // takes entries from oldest to newest, and the day it is now
+  pub fn new(entries: &[HistoryEntry], today: u64) -> Self {
+    let completed: Vec<&HistoryEntry> = entries
+      .iter()
+      .filter(|entry| entry.failure.is_none())
+      .collect();
+
+    let rolling = ROLLING_COUNTS
+      .iter()
+      .map(|&count| {
+        let start = completed.len().saturating_sub(count);
+        (count, Average::of(completed[start..].iter().copied()))
+      })
+      .collect();
+
+    let best = Mode::value_variants()
+      .iter()
+      .filter_map(|&mode| {
+        completed
+          .iter()
+          .filter(|entry| entry.mode == mode)
+          .max_by(|a, b| a.wpm.total_cmp(&b.wpm))
+          .map(|&entry| entry.clone())
+      })
+      .collect();
+
+    let mut days: Vec<DayStats> = vec![];
+    let mut by_day = completed.clone();
+    by_day.sort_by_key(|entry| entry.timestamp);
+    for group in by_day.chunk_by(|a, b| date::day(a.timestamp) == date::day(b.timestamp)) {
+      if let Some(average) = Average::of(group.iter().copied()) {
+        days.push(DayStats {
+          day: date::day(group[0].timestamp),
+          average,
+        });
+      }
+    }
+
+    let mut practiced: Vec<u64> = entries
+      .iter()
+      .map(|entry| date::day(entry.timestamp))
+      .collect();
+    practiced.sort_unstable();
+    practiced.dedup();
+    let (streak, longest_streak) = streaks(&practiced, today);
+
+    HistoryStats {
+      rolling,
+      best,
+      days,
+      tests: entries.len(),
+      time_practiced: entries.iter().map(HistoryEntry::time_spent).sum(),
+      streak,
+      longest_streak,
+    }
+  }
+}
+
+// the current and longest runs of consecutive days, from days in order
+fn streaks(days: &[u64], today: u64) -> (u64, u64) {
+  let mut longest = 0;
+  let mut run = 0;
+  let mut previous: Option<u64> = None;
+  for &day in days {
+    run = match previous {
+      Some(previous) if day == previous + 1 => run + 1,
+      _ => 1,
+    };
+    longest = longest.max(run);
+    previous = Some(day);
+  }
+  // the streak is still going if the last test was today or yesterday
+  let current = match previous {
+    Some(last) if last + 1 >= today => run,
+    _ => 0,
+  };
+  (current, longest)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::models::challenge::FailureReason;
+  use crate::util::date::SECONDS_PER_DAY;
+
+  fn create_entry(day: u64, mode: Mode, wpm: f32) -> HistoryEntry {
+    HistoryEntry {
+      timestamp: day * SECONDS_PER_DAY + 60,
+      mode,
+      length: 30,
+      word_list: "english".to_owned(),
+      seed: None,
+      wpm,
+      accuracy: wpm,
+      duration: Some(30.0),
+      failure: None,
+    }
+  }
+
+  #[test]
+  fn it_averages_and_finds_bests() {
+    let mut entries: Vec<HistoryEntry> = (0..20)
+      .map(|i| create_entry(100 + i / 5, Mode::Words, i as f32))
+      .collect();
+    entries.push(create_entry(104, Mode::Time, 50.0));
+    let mut failed = create_entry(104, Mode::Time, 90.0);
+    failed.failure = Some(FailureReason::UncorrectedMistake);
+    entries.push(failed);
+
+    let stats = HistoryStats::new(&entries, 104);
+    let [(10, Some(last_10)), (100, Some(last_100))] = stats.rolling[..] else {
+      panic!("expected both rolling averages");
+    };
+    assert_eq!(last_10.tests, 10);
+    // the last ten completed tests are words tests 11 to 19 and the time test
+    assert_eq!(last_10.wpm, 18.5);
+    assert_eq!(last_100.tests, 21);
+
+    let best: Vec<(Mode, f32)> = stats.best.iter().map(|e| (e.mode, e.wpm)).collect();
+    assert_eq!(best, vec![(Mode::Words, 19.0), (Mode::Time, 50.0)]);
+
+    let days: Vec<(u64, usize)> = stats
+      .days
+      .iter()
+      .map(|day| (day.day, day.average.tests))
+      .collect();
+    assert_eq!(days, vec![(100, 5), (101, 5), (102, 5), (103, 5), (104, 1)]);
+    assert_eq!(stats.tests, 22);
+    assert_eq!(stats.time_practiced, Duration::from_secs(22 * 30));
+  }
+
+  #[test]
+  fn it_counts_streaks() {
+    assert_eq!(streaks(&[], 10), (0, 0));
+    assert_eq!(streaks(&[1, 2, 3, 5, 6], 6), (2, 3));
+    // a streak isn't over until a day goes by without practice
+    assert_eq!(streaks(&[1, 2, 3, 5, 6], 7), (2, 3));
+    assert_eq!(streaks(&[1, 2, 3, 5, 6], 8), (0, 3));
+  }
+}
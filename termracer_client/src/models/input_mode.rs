@@ -0,0 +1,24 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InputMode {
+  // lines flow into each other, typing the separator at the end of a line
+  // moves on to the next one
+  Continuous,
+  // each line must be completed and then ended by pressing enter, which suits
+  // multi-line text such as code
+  Line,
+}
+
+impl fmt::Display for InputMode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = self
+      .to_possible_value()
+      .expect("ERROR: Failed to name input mode.");
+    write!(f, "{}", name.get_name())
+  }
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+// what the length of a test is measured in
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Mode {
+  // type a fixed number of words
+  Words,
+  // type as much as possible within a number of seconds
+  Time,
+}
+
+impl Mode {
+  // lengths offered for the mode
+  pub fn lengths(self) -> &'static [usize] {
+    match self {
+      Mode::Words => &[10, 25, 50, 100],
+      Mode::Time => &[15, 30, 60, 120],
+    }
+  }
+
+  pub fn default_length(self) -> usize {
+    match self {
+      Mode::Words => 25,
+      Mode::Time => 30,
+    }
+  }
+}
+
+impl fmt::Display for Mode {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = self
+      .to_possible_value()
+      .expect("ERROR: Failed to name mode.");
+    write!(f, "{}", name.get_name())
+  }
+}
@@ -3,6 +3,8 @@ use std::ops::Add;
 pub struct Progress {
   pub correct: usize,
   pub incorrect: usize,
+  // graphemes typed past the end of words, which aren't part of the total
+  pub extra: usize,
   pub total: usize,
 }
 
@@ -12,6 +14,7 @@ impl Add for Progress {
     Progress {
       correct: self.correct + other.correct,
       incorrect: self.incorrect + other.incorrect,
+      extra: self.extra + other.extra,
       total: self.total + other.total,
     }
   }
@@ -0,0 +1,65 @@
+use std::fmt;
+
+use clap::ValueEnum;
+use crossterm::style::Color;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+  Default,
+  // avoids relying on telling red and green apart
+  HighContrast,
+  Monochrome,
+}
+
+// colors used by the views
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Palette {
+  pub text: Color,
+  pub highlight: Color,
+  pub correct: Color,
+  pub incorrect: Color,
+  pub missed: Color,
+  pub extra: Color,
+}
+
+impl Theme {
+  pub fn palette(self) -> Palette {
+    match self {
+      Theme::Default => Palette {
+        text: Color::White,
+        highlight: Color::White,
+        correct: Color::Green,
+        incorrect: Color::Red,
+        missed: Color::DarkYellow,
+        extra: Color::DarkRed,
+      },
+      Theme::HighContrast => Palette {
+        text: Color::White,
+        highlight: Color::Yellow,
+        correct: Color::Cyan,
+        incorrect: Color::Magenta,
+        missed: Color::Yellow,
+        extra: Color::DarkMagenta,
+      },
+      Theme::Monochrome => Palette {
+        text: Color::Grey,
+        highlight: Color::White,
+        correct: Color::White,
+        incorrect: Color::DarkGrey,
+        missed: Color::DarkGrey,
+        extra: Color::DarkGrey,
+      },
+    }
+  }
+}
+
+impl fmt::Display for Theme {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let name = self
+      .to_possible_value()
+      .expect("ERROR: Failed to name theme.");
+    write!(f, "{}", name.get_name())
+  }
+}
@@ -0,0 +1,127 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::progress::Progress;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GraphemeState {
+  Untyped,
+  Correct,
+  Incorrect,
+  // skipped over by moving on to the next word early
+  Missed,
+  // typed past the end of a word
+  Extra,
+}
+
+// a word in the text, including its trailing separator (if any), along with
+// the typing state of each of its graphemes
+pub struct Word {
+  pub graphemes: Vec<String>,
+  pub states: Vec<GraphemeState>,
+  // graphemes typed past the end of the word, displayed before the separator
+  pub extra: Vec<String>,
+}
+
+impl Word {
+  pub fn new(text: &str) -> Self {
+    let graphemes: Vec<String> = text.graphemes(true).map(String::from).collect();
+    let length = graphemes.len();
+    Word {
+      graphemes,
+      states: vec![GraphemeState::Untyped; length],
+      extra: vec![],
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.graphemes.len()
+  }
+
+  // number of graphemes excluding the trailing separator
+  pub fn letter_count(&self) -> usize {
+    if self.has_separator() {
+      self.len() - 1
+    } else {
+      self.len()
+    }
+  }
+
+  pub fn has_separator(&self) -> bool {
+    self
+      .graphemes
+      .last()
+      .is_some_and(|g| g.contains(char::is_whitespace))
+  }
+
+  // whether any typed part of the word is wrong
+  pub fn has_mistakes(&self) -> bool {
+    !self.extra.is_empty()
+      || self
+        .states
+        .iter()
+        .any(|&state| state == GraphemeState::Incorrect || state == GraphemeState::Missed)
+  }
+
+  // the word as it appears on screen, with extra graphemes inserted before the
+  // separator
+  pub fn display_len(&self) -> usize {
+    self.len() + self.extra.len()
+  }
+
+  pub fn display_index(&self, index: usize) -> usize {
+    if index < self.letter_count() {
+      index
+    } else {
+      index + self.extra.len()
+    }
+  }
+
+  pub fn display_grapheme(&self, display_index: usize) -> (&str, GraphemeState) {
+    let letters = self.letter_count();
+    if display_index < letters {
+      (&self.graphemes[display_index], self.states[display_index])
+    } else if display_index < letters + self.extra.len() {
+      (&self.extra[display_index - letters], GraphemeState::Extra)
+    } else {
+      let index = display_index - self.extra.len();
+      (&self.graphemes[index], self.states[index])
+    }
+  }
+
+  pub fn progress(&self) -> Progress {
+    let count = |target: GraphemeState| self.states.iter().filter(|&&x| x == target).count();
+    Progress {
+      correct: count(GraphemeState::Correct),
+      incorrect: count(GraphemeState::Incorrect) + count(GraphemeState::Missed),
+      extra: self.extra.len(),
+      total: self.len(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_splits_graphemes() {
+    let word = Word::new("nai\u{308}ve ");
+
+    assert_eq!(word.graphemes, vec!["n", "a", "i\u{308}", "v", "e", " "]);
+    assert_eq!(word.states, vec![GraphemeState::Untyped; 6]);
+    assert_eq!(word.letter_count(), 5);
+  }
+
+  #[test]
+  fn it_displays_extra_graphemes() {
+    let mut word = Word::new("ab ");
+    word.extra = vec!["c".to_owned(), "d".to_owned()];
+
+    assert_eq!(word.display_len(), 5);
+    assert_eq!(word.display_index(1), 1);
+    assert_eq!(word.display_index(2), 4);
+    assert_eq!(word.display_grapheme(1).0, "b");
+    assert_eq!(word.display_grapheme(3), ("d", GraphemeState::Extra));
+    assert_eq!(word.display_grapheme(4).0, " ");
+  }
+}
@@ -1,2 +1,11 @@
 pub mod cli;
-pub mod throttler;
+pub mod config;
+pub mod csv;
+pub mod date;
+pub mod history;
+pub mod history_formats;
+pub mod keyboard_layouts;
+pub mod paths;
+pub mod report;
+pub mod stopwatch;
+pub mod word_lists;
@@ -1,16 +1,295 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::builder::RangedU64ValueParser;
+use clap::{Args, Parser, Subcommand};
+
+use super::config::Config;
+use super::date::{self, SECONDS_PER_DAY};
+use super::history::HistoryFilter;
+use super::report::OutputFormat;
+use crate::models::challenge::Challenge;
+use crate::models::correction_policy::CorrectionPolicyKind;
+use crate::models::game_settings::GameSettings;
+use crate::models::input_mode::InputMode;
+use crate::models::mode::Mode;
+use crate::models::theme::Theme;
+
+pub const DEFAULT_FPS: u32 = 60;
+pub const MAX_FPS: u32 = 240;
 
 #[derive(Parser)]
 #[command(name = "TermRacer Client")]
 pub struct Cli {
+  /// Opens the menu when no command is given
   #[command(subcommand)]
-  pub command: Commands,
+  pub command: Option<Commands>,
+  /// Config file to use instead of the one in the config directory
+  #[arg(long, global = true)]
+  pub config: Option<PathBuf>,
+  /// Frames drawn per second
+  #[arg(
+    long,
+    global = true,
+    value_parser = clap::value_parser!(u32).range(1..=MAX_FPS as i64)
+  )]
+  fps: Option<u32>,
+  /// Print how well frames kept up with the target rate on exit
+  #[arg(long, global = true)]
+  pub frame_timing: bool,
+}
+
+impl Cli {
+  pub fn fps(&self, config: &Config) -> u32 {
+    self.fps.or(config.fps).unwrap_or(DEFAULT_FPS)
+  }
 }
 
 #[derive(Subcommand)]
 pub enum Commands {
-  Solo {
-    #[arg(short = 'w', long)]
-    word_count: usize,
-  },
+  /// Starts a test straight away, skipping the menu
+  Solo(SoloArgs),
+  /// Prints the configuration in effect, checking the config file
+  Config,
+  /// Moves results between machines
+  #[command(subcommand)]
+  History(HistoryCommands),
+  /// Shows how practice is going, from the history
+  Stats,
+}
+
+#[derive(Subcommand)]
+pub enum HistoryCommands {
+  /// Prints the history, or writes it to a file
+  Export(ExportArgs),
+  /// Adds the tests in an exported history that aren't in the history
+  /// already
+  Import(ImportArgs),
+}
+
+#[derive(Args)]
+pub struct ExportArgs {
+  /// Format to write the history in
+  #[arg(short = 'f', long, value_enum, default_value = "json")]
+  pub format: OutputFormat,
+  /// Write the history to a file instead of printing it
+  #[arg(long)]
+  pub output_file: Option<PathBuf>,
+  #[command(flatten)]
+  pub filter: FilterArgs,
+}
+
+#[derive(Args)]
+pub struct ImportArgs {
+  /// A file exported from another history, or the history file itself
+  pub file: PathBuf,
+  /// Format of the file, worked out from its extension if it isn't given
+  #[arg(short = 'f', long, value_enum)]
+  format: Option<OutputFormat>,
+  #[command(flatten)]
+  pub filter: FilterArgs,
+}
+
+impl ImportArgs {
+  pub fn format(&self) -> OutputFormat {
+    self.format.unwrap_or_else(|| {
+      match self
+        .file
+        .extension()
+        .and_then(|extension| extension.to_str())
+      {
+        Some(extension) if extension.eq_ignore_ascii_case("csv") => OutputFormat::Csv,
+        _ => OutputFormat::Json,
+      }
+    })
+  }
+}
+
+// which tests in the history to include
+#[derive(Args)]
+pub struct FilterArgs {
+  /// Only tests taken on or after this date, e.g. 2023-05-01
+  #[arg(long, value_parser = date::parse_date)]
+  since: Option<u64>,
+  /// Only tests taken on or before this date
+  #[arg(long, value_parser = date::parse_date)]
+  until: Option<u64>,
+  /// Only tests of this mode
+  #[arg(short = 'm', long, value_enum)]
+  mode: Option<Mode>,
+}
+
+impl FilterArgs {
+  pub fn filter(&self) -> HistoryFilter {
+    HistoryFilter {
+      from: self.since,
+      // the whole of the last day is included
+      to: self.until.map(|until| until + SECONDS_PER_DAY),
+      mode: self.mode,
+    }
+  }
+}
+
+// options that are left out are taken from the config
+#[derive(Args)]
+pub struct SoloArgs {
+  /// Whether the test is a number of words or seconds
+  #[arg(short = 'm', long, value_enum)]
+  mode: Option<Mode>,
+  /// Number of words or seconds, depending on the mode
+  #[arg(
+    short = 'l',
+    long,
+    visible_alias = "word-count",
+    short_alias = 'w',
+    value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+  )]
+  length: Option<usize>,
+  /// Name of a list in the word lists directory, or a path to a file
+  #[arg(long)]
+  word_list: Option<String>,
+  /// Built-in layout, name of a file in the layouts directory, or a path to a
+  /// file
+  #[arg(long)]
+  layout: Option<String>,
+  /// Colors to draw with
+  #[arg(short = 't', long, value_enum)]
+  theme: Option<Theme>,
+  /// How lines are moved on from
+  #[arg(short = 'i', long, value_enum)]
+  input_mode: Option<InputMode>,
+  /// Which mistakes can be corrected
+  #[arg(short = 'c', long, value_enum)]
+  correction_policy: Option<CorrectionPolicyKind>,
+  /// Fail on the first mistake left uncorrected
+  #[arg(long)]
+  sudden_death: bool,
+  /// Fail as soon as accuracy drops below this percentage
+  #[arg(long, value_parser = parse_percentage)]
+  min_accuracy: Option<f32>,
+  /// Generate the same text as another test with the seed
+  #[arg(long)]
+  seed: Option<u64>,
+  /// Print the results for scripts instead of people
+  #[arg(short = 'o', long, value_enum)]
+  pub output: Option<OutputFormat>,
+  /// Write the results to a file instead, as json unless another output is
+  /// given
+  #[arg(long)]
+  pub output_file: Option<PathBuf>,
+  /// Don't print the results for people
+  #[arg(short = 'q', long)]
+  pub quiet: bool,
+}
+
+impl SoloArgs {
+  pub fn settings(&self, config: &Config) -> GameSettings {
+    let defaults = config.settings();
+    let mode = self.mode.unwrap_or(defaults.mode);
+    // a length from the config is in the config's mode, so it's no use for
+    // another one
+    let length = if mode == defaults.mode {
+      defaults.length
+    } else {
+      mode.default_length()
+    };
+    GameSettings {
+      mode,
+      length: self.length.unwrap_or(length),
+      word_list: self.word_list.clone().unwrap_or(defaults.word_list),
+      layout: self.layout.clone().unwrap_or(defaults.layout),
+      theme: self.theme.unwrap_or(defaults.theme),
+      border_style: defaults.border_style,
+      input_mode: self.input_mode.unwrap_or(defaults.input_mode),
+      correction_policy: self.correction_policy.unwrap_or(defaults.correction_policy),
+      challenge: Challenge {
+        sudden_death: self.sudden_death || defaults.challenge.sudden_death,
+        min_accuracy: self.min_accuracy.or(defaults.challenge.min_accuracy),
+      },
+      seed: self.seed,
+    }
+  }
+
+  // the format results are written in for scripts, if they are
+  pub fn output_format(&self) -> Option<OutputFormat> {
+    match (self.output, &self.output_file) {
+      (None, Some(_)) => Some(OutputFormat::Json),
+      (output, _) => output,
+    }
+  }
+}
+
+fn parse_percentage(s: &str) -> Result<f32, String> {
+  let percentage: f32 = s.parse().map_err(|_| format!("`{}` isn't a number", s))?;
+  if (0.0..=100.0).contains(&percentage) {
+    Ok(percentage)
+  } else {
+    Err("must be between 0 and 100".to_owned())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse_settings(args: &[&str], config: &Config) -> GameSettings {
+    let cli = Cli::try_parse_from(["termracer"].iter().chain(args)).unwrap();
+    match cli.command {
+      Some(Commands::Solo(args)) => args.settings(config),
+      _ => panic!("expected the solo command"),
+    }
+  }
+
+  #[test]
+  fn it_merges_args_over_config() {
+    let config = Config::parse("mode = \"time\"\ntheme = \"monochrome\"\nlength = 15").unwrap();
+
+    let settings = parse_settings(&["solo", "-t", "high-contrast"], &config);
+    assert_eq!(settings.mode, Mode::Time);
+    assert_eq!(settings.length, 15);
+    assert_eq!(settings.theme, Theme::HighContrast);
+
+    let settings = parse_settings(&["solo", "-m", "words"], &Config::default());
+    assert_eq!(settings.length, Mode::Words.default_length());
+    // the config's length is in seconds, not words
+    let settings = parse_settings(&["solo", "-m", "words"], &config);
+    assert_eq!(settings.length, Mode::Words.default_length());
+
+    let config = Config::parse("sudden_death = true\nmin_accuracy = 90.0").unwrap();
+    let settings = parse_settings(&["solo", "--min-accuracy", "95"], &config);
+    assert!(settings.challenge.sudden_death);
+    assert_eq!(settings.challenge.min_accuracy, Some(95.0));
+
+    assert!(Cli::try_parse_from(["termracer", "solo", "--length", "0"]).is_err());
+  }
+
+  #[test]
+  fn it_parses_history_filters() {
+    let args = [
+      "termracer",
+      "history",
+      "export",
+      "--since",
+      "2023-04-30",
+      "--until",
+      "2023-04-30",
+    ];
+    let cli = Cli::try_parse_from(args).unwrap();
+    let Some(Commands::History(HistoryCommands::Export(args))) = cli.command else {
+      panic!("expected the history export command");
+    };
+    let filter = args.filter.filter();
+    assert_eq!(filter.from, Some(1_682_812_800));
+    assert_eq!(filter.to, Some(1_682_899_200));
+    assert_eq!(args.format, OutputFormat::Json);
+
+    let args = ["termracer", "history", "import", "old.CSV"];
+    let Some(Commands::History(HistoryCommands::Import(args))) =
+      Cli::try_parse_from(args).unwrap().command
+    else {
+      panic!("expected the history import command");
+    };
+    assert_eq!(args.format(), OutputFormat::Csv);
+
+    assert!(Cli::try_parse_from(["termracer", "history", "export", "--since", "may"]).is_err());
+  }
 }
@@ -0,0 +1,192 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::cli::{DEFAULT_FPS, MAX_FPS};
+use super::keyboard_layouts::KeyboardLayout;
+use super::paths;
+use super::word_lists::WordList;
+use crate::framework::border::BorderStyle;
+use crate::models::challenge::Challenge;
+use crate::models::correction_policy::CorrectionPolicyKind;
+use crate::models::game_settings::GameSettings;
+use crate::models::input_mode::InputMode;
+use crate::models::mode::Mode;
+use crate::models::theme::Theme;
+
+const CONFIG_FILE: &str = "config.toml";
+
+// defaults for options that aren't given on the command line, every one of
+// which can be left out
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+  pub mode: Option<Mode>,
+  // number of words or seconds, depending on the mode
+  #[serde(alias = "word_count")]
+  pub length: Option<usize>,
+  pub word_list: Option<String>,
+  pub layout: Option<String>,
+  pub theme: Option<Theme>,
+  pub border_style: Option<BorderStyle>,
+  pub input_mode: Option<InputMode>,
+  pub correction_policy: Option<CorrectionPolicyKind>,
+  pub sudden_death: Option<bool>,
+  // a percentage
+  pub min_accuracy: Option<f32>,
+  pub fps: Option<u32>,
+}
+
+impl Config {
+  // loads the file given, or else the one in the config directory if there is
+  // one, which is optional unlike a file that was asked for
+  pub fn load(path: Option<&Path>) -> Result<Self, String> {
+    match path {
+      Some(path) => Self::load_from(path),
+      None => match default_path() {
+        Some(path) if path.exists() => Self::load_from(&path),
+        _ => Ok(Config::default()),
+      },
+    }
+  }
+
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let config: Config = toml::from_str(text).map_err(|error| error.message().to_owned())?;
+    if config.length == Some(0) {
+      return Err("`length` must be at least 1".to_owned());
+    }
+    if let Some(min_accuracy) = config.min_accuracy {
+      if !(0.0..=100.0).contains(&min_accuracy) {
+        return Err("`min_accuracy` must be between 0 and 100".to_owned());
+      }
+    }
+    if let Some(fps) = config.fps {
+      if !(1..=MAX_FPS).contains(&fps) {
+        return Err(format!("`fps` must be between 1 and {}", MAX_FPS));
+      }
+    }
+    Ok(config)
+  }
+
+  // checks that the files the config names can be loaded, which isn't done
+  // when it's loaded in case they're never used
+  pub fn validate(&self) -> Result<(), String> {
+    if let Some(word_list) = &self.word_list {
+      WordList::load(word_list)?;
+    }
+    if let Some(layout) = &self.layout {
+      KeyboardLayout::load(layout)?;
+    }
+    Ok(())
+  }
+
+  // the settings tests start with when nothing else is given
+  pub fn settings(&self) -> GameSettings {
+    let defaults = GameSettings::default();
+    let mode = self.mode.unwrap_or(defaults.mode);
+    GameSettings {
+      mode,
+      length: self.length.unwrap_or(mode.default_length()),
+      word_list: self.word_list.clone().unwrap_or(defaults.word_list),
+      layout: self.layout.clone().unwrap_or(defaults.layout),
+      theme: self.theme.unwrap_or(defaults.theme),
+      border_style: self.border_style.unwrap_or(defaults.border_style),
+      input_mode: self.input_mode.unwrap_or(defaults.input_mode),
+      correction_policy: self.correction_policy.unwrap_or(defaults.correction_policy),
+      challenge: Challenge {
+        sudden_death: self.sudden_death.unwrap_or(defaults.challenge.sudden_death),
+        min_accuracy: self.min_accuracy.or(defaults.challenge.min_accuracy),
+      },
+      ..defaults
+    }
+  }
+
+  // every option with the value it ends up with, defaults included
+  pub fn effective(&self) -> Config {
+    let settings = self.settings();
+    Config {
+      mode: Some(settings.mode),
+      length: Some(settings.length),
+      word_list: Some(settings.word_list),
+      layout: Some(settings.layout),
+      theme: Some(settings.theme),
+      border_style: Some(settings.border_style),
+      input_mode: Some(settings.input_mode),
+      correction_policy: Some(settings.correction_policy),
+      sudden_death: Some(settings.challenge.sudden_death),
+      // no minimum is left out rather than given as a value
+      min_accuracy: settings.challenge.min_accuracy,
+      fps: Some(self.fps.unwrap_or(DEFAULT_FPS)),
+    }
+  }
+
+  pub fn to_toml(&self) -> String {
+    toml::to_string(self).expect("ERROR: Failed to write config.")
+  }
+
+  fn load_from(path: &Path) -> Result<Self, String> {
+    let text = fs::read_to_string(path)
+      .map_err(|error| format!("can't read config `{}`: {}", path.display(), error))?;
+    Self::parse(&text).map_err(|error| format!("invalid config `{}`: {}", path.display(), error))
+  }
+}
+
+// the config file used when none is given
+pub fn default_path() -> Option<PathBuf> {
+  paths::config_dir().map(|dir| dir.join(CONFIG_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_parses_configs() {
+    let config = Config::parse(
+      "mode = \"time\"\nword_count = 60\ntheme = \"high-contrast\"\nlayout = \"dvorak\"\n",
+    )
+    .unwrap();
+    assert_eq!(config.mode, Some(Mode::Time));
+    assert_eq!(config.length, Some(60));
+    assert_eq!(config.theme, Some(Theme::HighContrast));
+    assert_eq!(config.border_style, None);
+    assert_eq!(config.fps, None);
+
+    assert!(Config::parse("colour = \"red\"").is_err());
+    assert!(Config::parse("mode = \"sprint\"").is_err());
+    assert!(Config::parse("border_style = \"dotted\"").is_err());
+    assert!(Config::parse("fps = 0").is_err());
+    assert!(Config::parse("length = 0").is_err());
+    assert!(Config::parse("min_accuracy = 101.0").is_err());
+  }
+
+  #[test]
+  fn it_sets_challenges() {
+    let config =
+      Config::parse("input_mode = \"line\"\nsudden_death = true\nmin_accuracy = 95.0").unwrap();
+    let settings = config.settings();
+    assert_eq!(settings.input_mode, InputMode::Line);
+    assert_eq!(
+      settings.challenge,
+      Challenge {
+        sudden_death: true,
+        min_accuracy: Some(95.0),
+      }
+    );
+  }
+
+  #[test]
+  fn it_fills_in_defaults() {
+    let config = Config::parse("mode = \"time\"\nfps = 30").unwrap();
+    let settings = config.settings();
+    assert_eq!(settings.length, Mode::Time.default_length());
+    assert_eq!(settings.word_list, GameSettings::default().word_list);
+
+    let effective = config.effective();
+    assert_eq!(effective.fps, Some(30));
+    assert_eq!(effective.theme, Some(Theme::Default));
+    // the effective config can be loaded back in
+    assert_eq!(Config::parse(&effective.to_toml()).unwrap(), effective);
+  }
+}
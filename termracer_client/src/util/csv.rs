@@ -0,0 +1,81 @@
+use std::mem;
+
+// a line of comma separated fields, quoted where they need to be
+pub fn row<S: AsRef<str>>(fields: &[S]) -> String {
+  let fields: Vec<String> = fields.iter().map(|field| quote(field.as_ref())).collect();
+  fields.join(",")
+}
+
+// splits text into rows of fields, undoing the quoting rows are written with
+// and skipping blank lines
+pub fn parse(text: &str) -> Result<Vec<Vec<String>>, String> {
+  let mut rows = vec![];
+  let mut row = vec![];
+  let mut field = String::new();
+  let mut quoted = false;
+  let mut chars = text.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '"' if quoted => {
+        if chars.peek() == Some(&'"') {
+          chars.next();
+          field.push('"');
+        } else {
+          quoted = false;
+        }
+      }
+      '"' if field.is_empty() => quoted = true,
+      _ if quoted => field.push(c),
+      ',' => row.push(mem::take(&mut field)),
+      '\r' if chars.peek() == Some(&'\n') => (),
+      '\n' => {
+        row.push(mem::take(&mut field));
+        rows.push(mem::take(&mut row));
+      }
+      _ => field.push(c),
+    }
+  }
+  if quoted {
+    return Err("a quoted field is never closed".to_owned());
+  }
+  if !field.is_empty() || !row.is_empty() {
+    row.push(field);
+    rows.push(row);
+  }
+  rows.retain(|row| row.len() > 1 || !row[0].is_empty());
+  Ok(rows)
+}
+
+fn quote(field: &str) -> String {
+  if field.contains([',', '"', '\n', '\r']) {
+    format!("\"{}\"", field.replace('"', "\"\""))
+  } else {
+    field.to_owned()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_quotes_fields() {
+    assert_eq!(row(&["a", "b c", ""]), "a,b c,");
+    assert_eq!(row(&["a,b", "say \"hi\""]), "\"a,b\",\"say \"\"hi\"\"\"");
+  }
+
+  #[test]
+  fn it_parses_rows() {
+    let fields = ["a,b", "say \"hi\"", "", "two\nlines"];
+    let text = format!("{}\r\n\nx,y\n", row(&fields));
+    assert_eq!(
+      parse(&text).unwrap(),
+      vec![
+        fields.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        vec!["x".to_owned(), "y".to_owned()],
+      ]
+    );
+    assert_eq!(parse("a,b").unwrap(), vec![vec!["a", "b"]]);
+    assert!(parse("\"a,b").is_err());
+  }
+}
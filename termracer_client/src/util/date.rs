@@ -0,0 +1,94 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+// the current unix timestamp
+pub fn now() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |duration| duration.as_secs())
+}
+
+// days since the unix epoch, in utc like the dates
+pub fn day(timestamp: u64) -> u64 {
+  timestamp / SECONDS_PER_DAY
+}
+
+// formats a unix timestamp as a utc date, e.g. 2023-05-01
+pub fn format_date(timestamp: u64) -> String {
+  let (year, month, day) = civil_from_days(day(timestamp) as i64);
+  format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// parses a date like 2023-05-01 into the timestamp of the start of that day
+// in utc
+pub fn parse_date(s: &str) -> Result<u64, String> {
+  let invalid = || format!("`{}` isn't a date like 2023-05-01", s);
+  let parts: Vec<&str> = s.split('-').collect();
+  let [year, month, day] = parts[..] else {
+    return Err(invalid());
+  };
+  let year: i64 = year.parse().map_err(|_| invalid())?;
+  let month: u32 = month.parse().map_err(|_| invalid())?;
+  let day: u32 = day.parse().map_err(|_| invalid())?;
+  let days = days_from_civil(year, month, day);
+  // dates past the end of the month come back as a different date
+  if days < 0 || civil_from_days(days) != (year, month, day) {
+    return Err(invalid());
+  }
+  Ok(days as u64 * SECONDS_PER_DAY)
+}
+
+// the inverse of civil_from_days, see
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+  let year = if month <= 2 { year - 1 } else { year };
+  let era = year.div_euclid(400);
+  let year_of_era = year.rem_euclid(400);
+  let shifted_month = if month > 2 { month - 3 } else { month + 9 } as i64;
+  let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146097 + day_of_era - 719468
+}
+
+// converts days since the unix epoch into a (year, month, day) date, see
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+  let z = days + 719468;
+  let era = z.div_euclid(146097);
+  let day_of_era = z.rem_euclid(146097);
+  let year_of_era =
+    (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let shifted_month = (5 * day_of_year + 2) / 153;
+  let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+  let month = if shifted_month < 10 {
+    shifted_month + 3
+  } else {
+    shifted_month - 9
+  } as u32;
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_formats_dates() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(951_782_400), "2000-02-29");
+    assert_eq!(format_date(1_682_899_199), "2023-04-30");
+  }
+
+  #[test]
+  fn it_parses_dates() {
+    assert_eq!(parse_date("1970-01-01"), Ok(0));
+    assert_eq!(parse_date("2000-02-29"), Ok(951_782_400));
+    assert_eq!(parse_date("2023-04-30"), Ok(1_682_812_800));
+    assert!(parse_date("2023-02-29").is_err());
+    assert!(parse_date("2023-13-01").is_err());
+    assert!(parse_date("yesterday").is_err());
+  }
+}
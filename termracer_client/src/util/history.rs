@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use super::date;
+use super::paths;
+use crate::models::game_result::GameResult;
+use crate::models::game_settings::GameSettings;
+use crate::models::history_entry::HistoryEntry;
+use crate::models::mode::Mode;
+
+// history is stored as one json object per line so that recording a test is
+// just an append
+const HISTORY_FILE: &str = "history.jsonl";
+
+pub fn history_path() -> io::Result<PathBuf> {
+  paths::data_dir()
+    .map(|dir| dir.join(HISTORY_FILE))
+    .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))
+}
+
+// records the result of a test, returning the entry if one was recorded
+pub fn record(settings: &GameSettings, result: &GameResult) -> io::Result<Option<HistoryEntry>> {
+  match HistoryEntry::new(settings, result, date::now()) {
+    Some(entry) => {
+      append(&history_path()?, &entry)?;
+      Ok(Some(entry))
+    }
+    None => Ok(None),
+  }
+}
+
+// entries from oldest to newest, or none if nothing has been recorded yet
+pub fn load() -> io::Result<Vec<HistoryEntry>> {
+  load_from(&history_path()?)
+}
+
+pub fn load_from(path: &Path) -> io::Result<Vec<HistoryEntry>> {
+  let file = match fs::File::open(path) {
+    Ok(file) => file,
+    Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+    Err(error) => return Err(error),
+  };
+
+  let mut entries = vec![];
+  for line in BufReader::new(file).lines() {
+    let line = line?;
+    if line.trim().is_empty() {
+      continue;
+    }
+    let entry = serde_json::from_str(&line)
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    entries.push(entry);
+  }
+  Ok(entries)
+}
+
+pub fn append(path: &Path, entry: &HistoryEntry) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  let line = serde_json::to_string(entry)
+    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+  let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+  writeln!(file, "{}", line)
+}
+
+// replaces everything in the history with the entries given
+pub fn save(path: &Path, entries: &[HistoryEntry]) -> io::Result<()> {
+  if let Some(dir) = path.parent() {
+    fs::create_dir_all(dir)?;
+  }
+  let mut text = String::new();
+  for entry in entries {
+    let line = serde_json::to_string(entry)
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    text.push_str(&line);
+    text.push('\n');
+  }
+  // written alongside and moved into place so that the history isn't lost if
+  // writing fails partway
+  let temp_path = path.with_extension("jsonl.tmp");
+  fs::write(&temp_path, text)?;
+  fs::rename(&temp_path, path)
+}
+
+// adds the entries that aren't in the history yet, keeping it in order, and
+// returns how many were added
+pub fn merge(history: &mut Vec<HistoryEntry>, entries: Vec<HistoryEntry>) -> usize {
+  let mut keys: HashSet<_> = history.iter().map(HistoryEntry::key).collect();
+  let count = history.len();
+  history.extend(entries.into_iter().filter(|entry| keys.insert(entry.key())));
+  history.sort_by_key(|entry| entry.timestamp);
+  history.len() - count
+}
+
+// which entries to include, where every part left out matches anything
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct HistoryFilter {
+  // first timestamp included
+  pub from: Option<u64>,
+  // first timestamp no longer included
+  pub to: Option<u64>,
+  pub mode: Option<Mode>,
+}
+
+impl HistoryFilter {
+  pub fn matches(&self, entry: &HistoryEntry) -> bool {
+    self.from.is_none_or(|from| entry.timestamp >= from)
+      && self.to.is_none_or(|to| entry.timestamp < to)
+      && self.mode.is_none_or(|mode| entry.mode == mode)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::env;
+  use std::process;
+
+  use super::*;
+  use crate::models::challenge::FailureReason;
+
+  fn create_entry(timestamp: u64, failure: Option<FailureReason>) -> HistoryEntry {
+    HistoryEntry {
+      timestamp,
+      mode: Mode::Words,
+      length: 25,
+      word_list: "english".to_owned(),
+      seed: Some(timestamp),
+      wpm: 80.0,
+      accuracy: 97.5,
+      duration: Some(20.0),
+      failure,
+    }
+  }
+
+  #[test]
+  fn it_appends_and_loads_entries() {
+    let path = env::temp_dir().join(format!("termracer_history_{}.jsonl", process::id()));
+    let _ = fs::remove_file(&path);
+    let entries = vec![
+      create_entry(1, None),
+      create_entry(2, Some(FailureReason::UncorrectedMistake)),
+    ];
+
+    assert_eq!(load_from(&path).unwrap(), vec![]);
+    for entry in &entries {
+      append(&path, entry).unwrap();
+    }
+    assert_eq!(load_from(&path).unwrap(), entries);
+    save(&path, &entries[1..]).unwrap();
+    assert_eq!(load_from(&path).unwrap(), entries[1..]);
+
+    fs::remove_file(&path).unwrap();
+  }
+
+  #[test]
+  fn it_merges_without_duplicates() {
+    let mut history = vec![create_entry(1, None), create_entry(3, None)];
+    let mut time_entry = create_entry(3, None);
+    time_entry.mode = Mode::Time;
+    let imported = vec![
+      create_entry(3, None),
+      create_entry(2, None),
+      create_entry(2, None),
+      time_entry.clone(),
+    ];
+
+    assert_eq!(merge(&mut history, imported), 2);
+    let timestamps: Vec<u64> = history.iter().map(|entry| entry.timestamp).collect();
+    assert_eq!(timestamps, vec![1, 2, 3, 3]);
+    assert_eq!(history[3], time_entry);
+  }
+
+  #[test]
+  fn it_filters_entries() {
+    let filter = HistoryFilter {
+      from: Some(10),
+      to: Some(20),
+      mode: Some(Mode::Words),
+    };
+    assert!(filter.matches(&create_entry(10, None)));
+    assert!(!filter.matches(&create_entry(20, None)));
+    assert!(!filter.matches(&create_entry(9, None)));
+    let mut entry = create_entry(15, None);
+    entry.mode = Mode::Time;
+    assert!(!filter.matches(&entry));
+    assert!(HistoryFilter::default().matches(&entry));
+  }
+}
@@ -0,0 +1,250 @@
+use std::str::FromStr;
+
+use clap::ValueEnum;
+
+use super::csv;
+use super::date::format_date;
+use super::report::OutputFormat;
+use crate::models::challenge::FailureReason;
+use crate::models::history_entry::HistoryEntry;
+use crate::models::mode::Mode;
+
+// the date is only there for people reading the file, and is ignored when
+// it's imported
+const CSV_HEADER: [&str; 11] = [
+  "timestamp",
+  "date",
+  "mode",
+  "length",
+  "word_list",
+  "seed",
+  "wpm",
+  "accuracy",
+  "duration",
+  "failure",
+  "min_accuracy",
+];
+
+// history for moving to another machine, as an array of entries in json or a
+// line per entry in csv
+pub fn export(entries: &[HistoryEntry], format: OutputFormat) -> String {
+  match format {
+    OutputFormat::Json => {
+      let json = serde_json::to_string_pretty(entries).expect("ERROR: Failed to write history.");
+      format!("{}\n", json)
+    }
+    OutputFormat::Csv => {
+      let mut text = csv::row(&CSV_HEADER);
+      text.push('\n');
+      for entry in entries {
+        text.push_str(&csv::row(&csv_fields(entry)));
+        text.push('\n');
+      }
+      text
+    }
+  }
+}
+
+// reads exported history back in -- the history file itself can be imported
+// as json too, since it's a json object per line
+pub fn import(text: &str, format: OutputFormat) -> Result<Vec<HistoryEntry>, String> {
+  match format {
+    OutputFormat::Json if text.trim_start().starts_with('[') => {
+      serde_json::from_str(text).map_err(|error| error.to_string())
+    }
+    OutputFormat::Json => text
+      .lines()
+      .enumerate()
+      .filter(|(_, line)| !line.trim().is_empty())
+      .map(|(index, line)| {
+        serde_json::from_str(line).map_err(|error| format!("line {}: {}", index + 1, error))
+      })
+      .collect(),
+    OutputFormat::Csv => import_csv(text),
+  }
+}
+
+fn csv_fields(entry: &HistoryEntry) -> Vec<String> {
+  let failure = entry.failure.map_or("", |failure| failure.id());
+  let min_accuracy = match entry.failure {
+    Some(FailureReason::LowAccuracy { min_accuracy, .. }) => min_accuracy.to_string(),
+    _ => String::new(),
+  };
+  vec![
+    entry.timestamp.to_string(),
+    format_date(entry.timestamp),
+    entry.mode.to_string(),
+    entry.length.to_string(),
+    entry.word_list.clone(),
+    entry.seed.map_or(String::new(), |seed| seed.to_string()),
+    entry.wpm.to_string(),
+    entry.accuracy.to_string(),
+    entry
+      .duration
+      .map_or(String::new(), |duration| duration.to_string()),
+    failure.to_owned(),
+    min_accuracy,
+  ]
+}
+
+// columns are found by their name in the header, so they can be in any order
+// and the optional ones can be left out
+fn import_csv(text: &str) -> Result<Vec<HistoryEntry>, String> {
+  let mut rows = csv::parse(text)?.into_iter();
+  let Some(header) = rows.next() else {
+    return Ok(vec![]);
+  };
+  let column = |name: &str| header.iter().position(|column| column.trim() == name);
+  let required = |name: &str| column(name).ok_or_else(|| format!("missing column `{}`", name));
+  let columns = Columns {
+    timestamp: required("timestamp")?,
+    mode: required("mode")?,
+    length: required("length")?,
+    word_list: required("word_list")?,
+    seed: column("seed"),
+    wpm: required("wpm")?,
+    accuracy: required("accuracy")?,
+    duration: column("duration"),
+    failure: column("failure"),
+    min_accuracy: column("min_accuracy"),
+  };
+
+  rows
+    .enumerate()
+    .map(|(index, row)| {
+      columns
+        .entry(&row)
+        .map_err(|error| format!("row {}: {}", index + 1, error))
+    })
+    .collect()
+}
+
+// where each field is in a csv row
+struct Columns {
+  timestamp: usize,
+  mode: usize,
+  length: usize,
+  word_list: usize,
+  seed: Option<usize>,
+  wpm: usize,
+  accuracy: usize,
+  duration: Option<usize>,
+  failure: Option<usize>,
+  min_accuracy: Option<usize>,
+}
+
+impl Columns {
+  fn entry(&self, row: &[String]) -> Result<HistoryEntry, String> {
+    let field = |index: usize| row.get(index).map_or("", |field| field.trim());
+    let optional = |index: Option<usize>| index.map_or("", field);
+
+    let accuracy = parse(field(self.accuracy), "accuracy")?;
+    let failure = match optional(self.failure) {
+      "" => None,
+      "uncorrected-mistake" => Some(FailureReason::UncorrectedMistake),
+      "low-accuracy" => Some(FailureReason::LowAccuracy {
+        accuracy,
+        min_accuracy: parse(optional(self.min_accuracy), "min_accuracy")?,
+      }),
+      failure => return Err(format!("`{}` isn't a failure", failure)),
+    };
+    Ok(HistoryEntry {
+      timestamp: parse(field(self.timestamp), "timestamp")?,
+      mode: Mode::from_str(field(self.mode), false)
+        .map_err(|_| format!("`{}` isn't a mode", field(self.mode)))?,
+      length: parse(field(self.length), "length")?,
+      word_list: field(self.word_list).to_owned(),
+      seed: match optional(self.seed) {
+        "" => None,
+        seed => Some(parse(seed, "seed")?),
+      },
+      wpm: parse(field(self.wpm), "wpm")?,
+      accuracy,
+      duration: match optional(self.duration) {
+        "" => None,
+        duration => Some(parse(duration, "duration")?),
+      },
+      failure,
+    })
+  }
+}
+
+fn parse<T: FromStr>(value: &str, name: &str) -> Result<T, String> {
+  value
+    .parse()
+    .map_err(|_| format!("`{}` isn't a valid {}", value, name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn create_entries() -> Vec<HistoryEntry> {
+    let entry = HistoryEntry {
+      timestamp: 1_682_899_199,
+      mode: Mode::Time,
+      length: 30,
+      word_list: "my words, mostly".to_owned(),
+      seed: Some(12),
+      wpm: 71.5,
+      accuracy: 88.0,
+      duration: Some(30.0),
+      failure: Some(FailureReason::LowAccuracy {
+        accuracy: 88.0,
+        min_accuracy: 90.0,
+      }),
+    };
+    let old_entry = HistoryEntry {
+      timestamp: 1_600_000_000,
+      mode: Mode::Words,
+      length: 25,
+      word_list: "english".to_owned(),
+      seed: None,
+      wpm: 60.0,
+      accuracy: 100.0,
+      duration: None,
+      failure: None,
+    };
+    vec![entry, old_entry]
+  }
+
+  #[test]
+  fn it_round_trips_exports() {
+    let entries = create_entries();
+    for format in [OutputFormat::Json, OutputFormat::Csv] {
+      let text = export(&entries, format);
+      assert_eq!(import(&text, format).unwrap(), entries);
+    }
+
+    let lines = export(&entries, OutputFormat::Csv);
+    assert!(lines
+      .lines()
+      .any(|line| line.starts_with("1682899199,2023-04-30,time,30,\"my words, mostly\",12,")));
+  }
+
+  #[test]
+  fn it_imports_history_files() {
+    let entries = create_entries();
+    let lines: Vec<String> = entries
+      .iter()
+      .map(|entry| serde_json::to_string(entry).unwrap())
+      .collect();
+    let text = lines.join("\n\n");
+    assert_eq!(import(&text, OutputFormat::Json).unwrap(), entries);
+  }
+
+  #[test]
+  fn it_reports_bad_csv_rows() {
+    let text = "mode,timestamp,length,word_list,wpm,accuracy\nwords,1,25,english,60,100\n";
+    let entries = import(text, OutputFormat::Csv).unwrap();
+    assert_eq!(entries[0].timestamp, 1);
+    assert_eq!(entries[0].seed, None);
+
+    let text = "timestamp,mode,length,word_list,wpm,accuracy\n1,words,25,english,60,100\n2,sprint,25,english,60,100\n";
+    assert_eq!(
+      import(text, OutputFormat::Csv),
+      Err("row 2: `sprint` isn't a mode".to_owned())
+    );
+    assert!(import("timestamp,mode\n1,words", OutputFormat::Csv).is_err());
+  }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use super::paths;
+
+// the layout the operating system is assumed to be typing in, which needs no
+// translating
+pub const DEFAULT_LAYOUT: &str = "qwerty";
+
+const LAYOUTS_DIR: &str = "layouts";
+const LAYOUT_EXTENSION: &str = "txt";
+
+// the unshifted keys of an ansi keyboard, row by row, which layouts are
+// written in the same shape as
+const QWERTY_ROWS: [&str; 4] = [
+  "`1234567890-=",
+  "qwertyuiop[]\\",
+  "asdfghjkl;'",
+  "zxcvbnm,./",
+];
+
+const BUILT_IN_LAYOUTS: [(&str, [&str; 4]); 5] = [
+  (DEFAULT_LAYOUT, QWERTY_ROWS),
+  (
+    "dvorak",
+    [
+      "`1234567890[]",
+      "',.pyfgcrl/=\\",
+      "aoeuidhtns-",
+      ";qjkxbmwvz",
+    ],
+  ),
+  (
+    "colemak",
+    [
+      "`1234567890-=",
+      "qwfpgjluy;[]\\",
+      "arstdhneio'",
+      "zxcvbkm,./",
+    ],
+  ),
+  (
+    "colemak-dh",
+    [
+      "`1234567890-=",
+      "qwfpbjluy;[]\\",
+      "arstgmneio'",
+      "zxcdvkh,./",
+    ],
+  ),
+  (
+    "workman",
+    [
+      "`1234567890-=",
+      "qdrwbjfup;[]\\",
+      "ashtgyneoi'",
+      "zxmcvkl,./",
+    ],
+  ),
+];
+
+// symbols typed with shift on a us keyboard, which every layout shares
+const SHIFTED_SYMBOLS: [(char, char); 21] = [
+  ('`', '~'),
+  ('1', '!'),
+  ('2', '@'),
+  ('3', '#'),
+  ('4', '$'),
+  ('5', '%'),
+  ('6', '^'),
+  ('7', '&'),
+  ('8', '*'),
+  ('9', '('),
+  ('0', ')'),
+  ('-', '_'),
+  ('=', '+'),
+  ('[', '{'),
+  (']', '}'),
+  ('\\', '|'),
+  (';', ':'),
+  ('\'', '"'),
+  (',', '<'),
+  ('.', '>'),
+  ('/', '?'),
+];
+
+// translates characters typed on a qwerty keyboard into the characters the
+// same keys would type in another layout, for practicing a layout without
+// switching the operating system over to it
+#[derive(Debug, PartialEq, Eq)]
+pub struct KeyboardLayout {
+  map: HashMap<char, char>,
+}
+
+impl KeyboardLayout {
+  // built-in layouts are loaded by name, and other layouts are named after
+  // their file in the layouts directory, but a path to a file anywhere can be
+  // given too
+  pub fn load(name: &str) -> Result<Self, String> {
+    if let Some((_, rows)) = BUILT_IN_LAYOUTS.iter().find(|(n, _)| *n == name) {
+      return Self::from_rows(rows);
+    }
+
+    let path = if paths::is_file_path(name, LAYOUT_EXTENSION) {
+      PathBuf::from(name)
+    } else {
+      layouts_dir()
+        .ok_or_else(|| format!("can't find keyboard layout `{}`", name))?
+        .join(format!("{}.{}", name, LAYOUT_EXTENSION))
+    };
+    let text = fs::read_to_string(&path)
+      .map_err(|error| format!("can't read keyboard layout `{}`: {}", path.display(), error))?;
+    Self::parse(&text)
+      .map_err(|error| format!("invalid keyboard layout `{}`: {}", path.display(), error))
+  }
+
+  // layout files have a line for each row of the keyboard, with the keys
+  // written in the order of the keys on a qwerty keyboard -- empty lines and
+  // lines starting with two slashes are skipped
+  pub fn parse(text: &str) -> Result<Self, String> {
+    let rows: Vec<&str> = text
+      .lines()
+      .map(str::trim)
+      .filter(|line| !line.is_empty() && !line.starts_with("//"))
+      .collect();
+    Self::from_rows(&rows)
+  }
+
+  pub fn translate(&self, c: char) -> char {
+    self.map.get(&c).copied().unwrap_or(c)
+  }
+
+  fn from_rows(rows: &[&str]) -> Result<Self, String> {
+    if rows.len() != QWERTY_ROWS.len() {
+      return Err(format!(
+        "expected {} rows of keys but found {}",
+        QWERTY_ROWS.len(),
+        rows.len()
+      ));
+    }
+
+    let mut map = HashMap::new();
+    for (row, (qwerty_row, layout_row)) in QWERTY_ROWS.iter().zip(rows).enumerate() {
+      let qwerty_keys: Vec<char> = qwerty_row.chars().collect();
+      let layout_keys: Vec<char> = layout_row.chars().collect();
+      if qwerty_keys.len() != layout_keys.len() {
+        return Err(format!(
+          "expected {} keys in row {} but found {}",
+          qwerty_keys.len(),
+          row + 1,
+          layout_keys.len()
+        ));
+      }
+
+      for (qwerty_key, layout_key) in qwerty_keys.into_iter().zip(layout_keys) {
+        map.insert(qwerty_key, layout_key);
+        if let (Some(qwerty_shifted), Some(layout_shifted)) =
+          (shifted(qwerty_key), shifted(layout_key))
+        {
+          map.insert(qwerty_shifted, layout_shifted);
+        }
+      }
+    }
+    // keys that type themselves don't need to be looked up
+    map.retain(|from, to| from != to);
+    Ok(KeyboardLayout { map })
+  }
+}
+
+// names of every layout that can be loaded, starting with the built-in ones
+pub fn available() -> Vec<String> {
+  let mut names: Vec<String> = layouts_dir()
+    .and_then(|dir| fs::read_dir(dir).ok())
+    .into_iter()
+    .flatten()
+    .filter_map(|entry| {
+      let path = entry.ok()?.path();
+      if path.extension()? != LAYOUT_EXTENSION {
+        return None;
+      }
+      Some(path.file_stem()?.to_string_lossy().into_owned())
+    })
+    .collect();
+  names.sort();
+  let built_in = BUILT_IN_LAYOUTS.iter().map(|(name, _)| name.to_string());
+  built_in.chain(names).collect()
+}
+
+fn shifted(key: char) -> Option<char> {
+  if key.is_ascii_lowercase() {
+    return Some(key.to_ascii_uppercase());
+  }
+  SHIFTED_SYMBOLS
+    .iter()
+    .find(|(unshifted, _)| *unshifted == key)
+    .map(|&(_, shifted)| shifted)
+}
+
+fn layouts_dir() -> Option<PathBuf> {
+  paths::data_dir().map(|dir| dir.join(LAYOUTS_DIR))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_translates_built_in_layouts() {
+    let dvorak = KeyboardLayout::load("dvorak").unwrap();
+    let typed: String = "qwerty QWERTY -=[]"
+      .chars()
+      .map(|c| dvorak.translate(c))
+      .collect();
+    assert_eq!(typed, "',.pyf \"<>PYF []/=");
+
+    let colemak = KeyboardLayout::load("colemak").unwrap();
+    let typed: String = "asdf;".chars().map(|c| colemak.translate(c)).collect();
+    assert_eq!(typed, "arsto");
+
+    assert!(KeyboardLayout::load(DEFAULT_LAYOUT).unwrap().map.is_empty());
+  }
+
+  #[test]
+  fn it_parses_layout_files() {
+    let text = "// swaps a and b\n\n`1234567890-=\nqwertyuiop[]\\\nbsdfghjkl;'\nzxcvanm,./\n";
+    let layout = KeyboardLayout::parse(text).unwrap();
+    assert_eq!(layout.translate('a'), 'b');
+    assert_eq!(layout.translate('B'), 'A');
+    assert_eq!(layout.translate('c'), 'c');
+
+    assert!(KeyboardLayout::parse("qwerty").is_err());
+    assert!(KeyboardLayout::parse("`1234567890-=\nqwerty\nasdfghjkl;'\nzxcvbnm,./").is_err());
+  }
+}
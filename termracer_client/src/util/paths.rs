@@ -0,0 +1,47 @@
+use std::env;
+use std::path::{self, Path, PathBuf};
+
+const APP_DIR: &str = "termracer";
+
+// where history and word lists are kept, following the xdg base directory
+// spec
+pub fn data_dir() -> Option<PathBuf> {
+  xdg_dir("XDG_DATA_HOME", ".local/share")
+}
+
+// where the config file is kept
+pub fn config_dir() -> Option<PathBuf> {
+  xdg_dir("XDG_CONFIG_HOME", ".config")
+}
+
+// whether a name is a path to a file rather than the name of one in a data
+// directory, which is the case when it has a directory or the extension
+pub fn is_file_path(name: &str, extension: &str) -> bool {
+  name.contains(path::MAIN_SEPARATOR)
+    || Path::new(name)
+      .extension()
+      .is_some_and(|name_extension| name_extension == extension)
+}
+
+fn xdg_dir(variable: &str, fallback: &str) -> Option<PathBuf> {
+  let base = match env::var_os(variable) {
+    // relative paths are invalid according to the spec and should be ignored
+    Some(dir) if PathBuf::from(&dir).is_absolute() => PathBuf::from(dir),
+    _ => PathBuf::from(env::var_os("HOME")?).join(fallback),
+  };
+  Some(base.join(APP_DIR))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_finds_file_paths() {
+    assert!(is_file_path("dvorak.txt", "txt"));
+    assert!(is_file_path("layouts/dvorak", "txt"));
+    assert!(!is_file_path("dvorak", "txt"));
+    assert!(!is_file_path("mytxt", "txt"));
+    assert!(!is_file_path("dvorak.text", "txt"));
+  }
+}
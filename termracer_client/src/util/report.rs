@@ -0,0 +1,178 @@
+use clap::ValueEnum;
+use serde::Serialize;
+
+use super::csv;
+use crate::models::challenge::FailureReason;
+use crate::models::game_result::{GameResult, Sample};
+use crate::models::game_settings::GameSettings;
+use crate::models::mode::Mode;
+
+// formats results can be written in for other programs to read
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+  Json,
+  // a header line and a line for each test
+  Csv,
+}
+
+const CSV_HEADER: [&str; 13] = [
+  "result",
+  "failure",
+  "mode",
+  "length",
+  "word_list",
+  "layout",
+  "seed",
+  "wpm",
+  "raw_wpm",
+  "accuracy",
+  "duration",
+  "wpm_per_second",
+  "raw_wpm_per_second",
+];
+
+// everything about how a test went, for scripts -- the stats are missing if
+// the test was aborted
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Report {
+  pub result: &'static str,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failure: Option<FailureReason>,
+  pub mode: Mode,
+  pub length: usize,
+  pub word_list: String,
+  pub layout: String,
+  pub seed: Option<u64>,
+  pub wpm: Option<f32>,
+  pub raw_wpm: Option<f32>,
+  pub accuracy: Option<f32>,
+  // in seconds
+  pub duration: Option<f32>,
+  pub samples: Vec<Sample>,
+}
+
+impl Report {
+  pub fn new(settings: &GameSettings, result: &GameResult) -> Self {
+    let (name, failure) = match result {
+      GameResult::Completed { .. } => ("completed", None),
+      GameResult::Failed { reason, .. } => ("failed", Some(*reason)),
+      GameResult::Aborted => ("aborted", None),
+    };
+    let stats = result.stats();
+    Report {
+      result: name,
+      failure,
+      mode: settings.mode,
+      length: settings.length,
+      word_list: settings.word_list.clone(),
+      layout: settings.layout.clone(),
+      seed: stats.map(|stats| stats.seed),
+      wpm: stats.map(|stats| stats.wpm),
+      raw_wpm: stats.map(|stats| stats.raw_wpm),
+      accuracy: stats.map(|stats| stats.accuracy),
+      duration: stats.map(|stats| stats.duration.as_secs_f32()),
+      samples: stats.map_or(vec![], |stats| stats.samples.clone()),
+    }
+  }
+
+  // the report as text ending in a newline
+  pub fn format(&self, format: OutputFormat) -> String {
+    match format {
+      OutputFormat::Json => {
+        let json = serde_json::to_string(self).expect("ERROR: Failed to write report.");
+        format!("{}\n", json)
+      }
+      OutputFormat::Csv => format!(
+        "{}\n{}\n",
+        csv::row(&CSV_HEADER),
+        csv::row(&self.csv_fields())
+      ),
+    }
+  }
+
+  fn csv_fields(&self) -> Vec<String> {
+    let optional = |value: Option<String>| value.unwrap_or_default();
+    let per_second = |speed: fn(&Sample) -> f32| {
+      let speeds: Vec<String> = self.samples.iter().map(|s| speed(s).to_string()).collect();
+      speeds.join(" ")
+    };
+    vec![
+      self.result.to_owned(),
+      optional(self.failure.map(|failure| failure.id().to_owned())),
+      self.mode.to_string(),
+      self.length.to_string(),
+      self.word_list.clone(),
+      self.layout.clone(),
+      optional(self.seed.map(|seed| seed.to_string())),
+      optional(self.wpm.map(|wpm| wpm.to_string())),
+      optional(self.raw_wpm.map(|wpm| wpm.to_string())),
+      optional(self.accuracy.map(|accuracy| accuracy.to_string())),
+      optional(self.duration.map(|duration| duration.to_string())),
+      per_second(|sample| sample.wpm),
+      per_second(|sample| sample.raw_wpm),
+    ]
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::Duration;
+
+  use super::*;
+  use crate::models::game_result::TestStats;
+
+  fn create_result() -> GameResult {
+    GameResult::Failed {
+      stats: TestStats {
+        wpm: 60.0,
+        raw_wpm: 72.5,
+        accuracy: 90.0,
+        duration: Duration::from_millis(2500),
+        seed: 7,
+        samples: vec![
+          Sample {
+            second: 1,
+            wpm: 48.0,
+            raw_wpm: 60.0,
+          },
+          Sample {
+            second: 2,
+            wpm: 54.0,
+            raw_wpm: 66.0,
+          },
+        ],
+      },
+      reason: FailureReason::UncorrectedMistake,
+    }
+  }
+
+  #[test]
+  fn it_formats_reports() {
+    let report = Report::new(&GameSettings::default(), &create_result());
+
+    let json: serde_json::Value = serde_json::from_str(&report.format(OutputFormat::Json)).unwrap();
+    assert_eq!(json["result"], "failed");
+    assert_eq!(json["failure"]["reason"], "uncorrected-mistake");
+    assert_eq!(json["seed"], 7);
+    assert_eq!(json["duration"], 2.5);
+    assert_eq!(json["samples"][1]["raw_wpm"], 66.0);
+
+    let csv = report.format(OutputFormat::Csv);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], csv::row(&CSV_HEADER));
+    assert_eq!(
+      lines[1],
+      "failed,uncorrected-mistake,words,25,english,qwerty,7,60,72.5,90,2.5,48 54,60 66"
+    );
+  }
+
+  #[test]
+  fn it_leaves_out_stats_of_aborted_tests() {
+    let report = Report::new(&GameSettings::default(), &GameResult::Aborted);
+    let json: serde_json::Value = serde_json::from_str(&report.format(OutputFormat::Json)).unwrap();
+    assert_eq!(json["wpm"], serde_json::Value::Null);
+    assert!(report
+      .format(OutputFormat::Csv)
+      .ends_with("aborted,,words,25,english,qwerty,,,,,,,\n"));
+  }
+}
@@ -0,0 +1,81 @@
+use std::time::{Duration, Instant};
+
+// measures time spent running, excluding any time spent paused
+// instants are passed in rather than read so events can be timed from when
+// they happened instead of when they were handled
+#[derive(Default)]
+pub struct Stopwatch {
+  // time accumulated before the current run
+  elapsed: Duration,
+  // when the current run began, if running
+  running_since: Option<Instant>,
+  started: bool,
+}
+
+impl Stopwatch {
+  pub fn new() -> Self {
+    Stopwatch {
+      elapsed: Duration::ZERO,
+      running_since: None,
+      started: false,
+    }
+  }
+
+  pub fn start(&mut self, now: Instant) {
+    self.started = true;
+    self.resume(now);
+  }
+
+  pub fn pause(&mut self, now: Instant) {
+    if let Some(since) = self.running_since.take() {
+      self.elapsed += now.saturating_duration_since(since);
+    }
+  }
+
+  pub fn resume(&mut self, now: Instant) {
+    if self.started && self.running_since.is_none() {
+      self.running_since = Some(now);
+    }
+  }
+
+  pub fn is_started(&self) -> bool {
+    self.started
+  }
+
+  pub fn is_running(&self) -> bool {
+    self.running_since.is_some()
+  }
+
+  pub fn elapsed(&self, now: Instant) -> Duration {
+    match self.running_since {
+      Some(since) => self.elapsed + now.saturating_duration_since(since),
+      None => self.elapsed,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_excludes_paused_time() {
+    let start = Instant::now();
+    let at = |secs: u64| start + Duration::from_secs(secs);
+    let mut stopwatch = Stopwatch::new();
+
+    stopwatch.resume(at(1));
+    assert!(!stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(at(2)), Duration::ZERO);
+
+    stopwatch.start(at(2));
+    assert_eq!(stopwatch.elapsed(at(5)), Duration::from_secs(3));
+
+    stopwatch.pause(at(5));
+    assert!(!stopwatch.is_running());
+    assert_eq!(stopwatch.elapsed(at(10)), Duration::from_secs(3));
+
+    stopwatch.resume(at(10));
+    assert_eq!(stopwatch.elapsed(at(12)), Duration::from_secs(5));
+  }
+}
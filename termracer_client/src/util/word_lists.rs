@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::PathBuf;
+
+use termracer_word_generator::word_generator;
+
+use super::paths;
+
+// the list built into the word generator
+pub const DEFAULT_WORD_LIST: &str = "english";
+
+const WORD_LISTS_DIR: &str = "word_lists";
+const WORD_LIST_EXTENSION: &str = "txt";
+
+// words to generate text from, either the built-in list or a user's own file
+// of whitespace separated words
+pub struct WordList {
+  words: Option<Vec<String>>,
+}
+
+impl WordList {
+  // lists are named after their file in the word lists directory, but a path
+  // to a file anywhere can be given too
+  pub fn load(name: &str) -> Result<Self, String> {
+    if name == DEFAULT_WORD_LIST {
+      return Ok(WordList { words: None });
+    }
+
+    let path = if paths::is_file_path(name, WORD_LIST_EXTENSION) {
+      PathBuf::from(name)
+    } else {
+      word_lists_dir()
+        .ok_or_else(|| format!("can't find word list `{}`", name))?
+        .join(format!("{}.{}", name, WORD_LIST_EXTENSION))
+    };
+    let text = fs::read_to_string(&path)
+      .map_err(|error| format!("can't read word list `{}`: {}", path.display(), error))?;
+    let words: Vec<String> = text.split_whitespace().map(String::from).collect();
+    if words.is_empty() {
+      return Err(format!("word list `{}` is empty", path.display()));
+    }
+    Ok(WordList { words: Some(words) })
+  }
+
+  // the same seed always generates the same text from the same list
+  pub fn generate(&self, count: usize, seed: u64) -> Vec<String> {
+    match &self.words {
+      Some(words) => word_generator::generate_words_from_seeded(words, count, seed),
+      None => word_generator::generate_words_seeded(count, seed),
+    }
+  }
+}
+
+// names of every list that can be loaded, starting with the built-in one
+pub fn available() -> Vec<String> {
+  let mut names: Vec<String> = word_lists_dir()
+    .and_then(|dir| fs::read_dir(dir).ok())
+    .into_iter()
+    .flatten()
+    .filter_map(|entry| {
+      let path = entry.ok()?.path();
+      if path.extension()? != WORD_LIST_EXTENSION {
+        return None;
+      }
+      Some(path.file_stem()?.to_string_lossy().into_owned())
+    })
+    .collect();
+  names.sort();
+  names.insert(0, DEFAULT_WORD_LIST.to_owned());
+  names
+}
+
+pub fn random_seed() -> u64 {
+  word_generator::random_seed()
+}
+
+fn word_lists_dir() -> Option<PathBuf> {
+  paths::data_dir().map(|dir| dir.join(WORD_LISTS_DIR))
+}
@@ -1,5 +1,15 @@
+pub mod bar_chart;
+pub mod border;
+pub mod bordered_box;
 pub mod line;
 pub mod line_block;
+pub mod menu_list;
+pub mod modal;
+pub mod overlay;
+pub mod page;
 pub mod progress_bar;
 pub mod stats_line;
+pub mod table;
+pub mod text_block;
+pub mod text_input;
 pub mod view;
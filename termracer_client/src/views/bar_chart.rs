@@ -0,0 +1,221 @@
+use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
+
+use super::view::View;
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+
+// bars a cell wide filled to the nearest eighth of a cell
+const BLOCKS: [&str; 8] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+
+// the bottom of the scale is a step below the lowest value, so that the
+// lowest bar still shows
+const SCALE_STEP: f32 = 10.0;
+
+const NO_DATA: &str = "Nothing to show yet";
+
+// a bar in a chart, which is left as a gap if it has no value
+pub struct Bar {
+  pub label: String,
+  pub value: Option<f32>,
+}
+
+// a column for each bar, showing as many of the last bars as fit against the
+// right edge, with the scale on the left and the labels of the first and last
+// bars underneath
+pub struct BarChart {
+  region_index: usize,
+  bars: Vec<Bar>,
+  fg: Color,
+  axis_fg: Color,
+}
+
+impl BarChart {
+  pub fn new(region_index: usize) -> Self {
+    BarChart {
+      region_index,
+      bars: vec![],
+      fg: Color::White,
+      axis_fg: Color::DarkGrey,
+    }
+  }
+
+  pub fn set_bars(&mut self, bars: Vec<Bar>) {
+    self.bars = bars;
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.fg = fg;
+  }
+}
+
+impl View for BarChart {
+  fn draw(&self, window: &mut Window) {
+    let region = *window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw bar chart -- invalid region.");
+    window.clear_region(self.region_index);
+
+    let values: Vec<f32> = self.bars.iter().filter_map(|bar| bar.value).collect();
+    if values.is_empty() {
+      window.draw(
+        NO_DATA,
+        self.axis_fg,
+        Color::Reset,
+        Coord { row: 0, col: 0 },
+        self.region_index,
+      );
+      return;
+    }
+
+    let (low, high) = scale(&values);
+    let (low_label, high_label) = (format!("{:.0}", low), format!("{:.0}", high));
+    let gutter = low_label.width().max(high_label.width()) + 1;
+    // the bottom row is kept for labels
+    let height = region.height.saturating_sub(1);
+    let columns = (region.width as usize).saturating_sub(gutter);
+    if height == 0 || columns == 0 {
+      return;
+    }
+
+    window.draw(
+      &format!("{:>1$}", high_label, gutter - 1),
+      self.axis_fg,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      self.region_index,
+    );
+    window.draw(
+      &format!("{:>1$}", low_label, gutter - 1),
+      self.axis_fg,
+      Color::Reset,
+      Coord {
+        row: height - 1,
+        col: 0,
+      },
+      self.region_index,
+    );
+
+    let shown = &self.bars[self.bars.len().saturating_sub(columns)..];
+    let start = gutter + columns - shown.len();
+    for (i, bar) in shown.iter().enumerate() {
+      let Some(value) = bar.value else {
+        continue;
+      };
+      let col = (start + i) as u16;
+      // in eighths of a cell, with at least a sliver for every value
+      let eighths = ((value - low) / (high - low) * height as f32 * 8.0).round() as u16;
+      let eighths = eighths.clamp(1, height * 8);
+      for row in 0..height {
+        let filled = eighths.saturating_sub(row * 8).min(8);
+        if filled == 0 {
+          break;
+        }
+        window.draw(
+          BLOCKS[filled as usize - 1],
+          self.fg,
+          Color::Reset,
+          Coord {
+            row: height - 1 - row,
+            col,
+          },
+          self.region_index,
+        );
+      }
+    }
+
+    // labels of the first and last bars, when they don't run into each other
+    let first = &shown[0].label;
+    window.draw(
+      first,
+      self.axis_fg,
+      Color::Reset,
+      Coord {
+        row: height,
+        col: start as u16,
+      },
+      self.region_index,
+    );
+    let last = &shown[shown.len() - 1].label;
+    let last_col = (region.width as usize).saturating_sub(last.width());
+    if shown.len() > 1 && last_col > start + first.width() {
+      window.draw(
+        last,
+        self.axis_fg,
+        Color::Reset,
+        Coord {
+          row: height,
+          col: last_col as u16,
+        },
+        self.region_index,
+      );
+    }
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
+
+// the values at the bottom and top of the chart
+fn scale(values: &[f32]) -> (f32, f32) {
+  let min = values.iter().copied().fold(f32::INFINITY, f32::min);
+  let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+  let low = ((min / SCALE_STEP).floor() * SCALE_STEP - SCALE_STEP).max(0.0);
+  let high = max.ceil().max(low + 1.0);
+  (low, high)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_draws_bars() {
+    let mut window = Window::new(12, 4);
+    let mut chart = BarChart::new(0);
+    chart.set_bars(
+      [Some(20.0), None, Some(40.0), Some(30.0)]
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| Bar {
+          label: format!("d{}", i),
+          value,
+        })
+        .collect(),
+    );
+    chart.draw(&mut window);
+
+    // the scale runs from 10 to 40 over three rows, and there isn't room for
+    // the last label
+    assert_eq!(
+      window.snapshot(),
+      vec![
+        "40        █ ",
+        "          ██",
+        "10      █ ██",
+        "        d0  ",
+      ]
+    );
+  }
+
+  #[test]
+  fn it_shows_the_latest_bars_that_fit() {
+    let mut window = Window::new(8, 3);
+    let mut chart = BarChart::new(0);
+    chart.set_bars(
+      (0..10)
+        .map(|i| Bar {
+          label: i.to_string(),
+          value: Some(i as f32 * 10.0),
+        })
+        .collect(),
+    );
+    chart.draw(&mut window);
+    assert_eq!(window.snapshot()[2], "   5   9");
+
+    chart.set_bars(vec![]);
+    chart.draw(&mut window);
+    assert_eq!(window.snapshot()[0], "Nothing ");
+  }
+}
@@ -0,0 +1,50 @@
+use crossterm::style::Color;
+
+use crate::framework::border::{bottom_edge, top_edge, BorderChars};
+use crate::framework::coord::Coord;
+use crate::framework::rect::Rect;
+use crate::framework::window::Window;
+
+// draws a border around rect, which is relative to the region
+pub fn draw_frame(
+  window: &mut Window,
+  region_index: usize,
+  rect: Rect,
+  chars: &BorderChars,
+  title: Option<&str>,
+  fg: Color,
+) {
+  if rect.width < 2 || rect.height < 2 {
+    return;
+  }
+  let width = rect.width as usize;
+  window.draw(
+    &top_edge(chars, width, title),
+    fg,
+    Color::Reset,
+    rect.coord,
+    region_index,
+  );
+  for row in 1..rect.height - 1 {
+    for col in [0, rect.width - 1] {
+      window.draw(
+        chars.vertical,
+        fg,
+        Color::Reset,
+        rect.coord + Coord { row, col },
+        region_index,
+      );
+    }
+  }
+  window.draw(
+    &bottom_edge(chars, width),
+    fg,
+    Color::Reset,
+    rect.coord
+      + Coord {
+        row: rect.height - 1,
+        col: 0,
+      },
+    region_index,
+  );
+}
@@ -0,0 +1,125 @@
+use crossterm::event::KeyEvent;
+use crossterm::style::Color;
+
+use super::view::{KeyEventHandleable, View};
+use crate::framework::border::{Border, BorderStyle};
+use crate::framework::window::Window;
+
+// a view with a border and an optional title around it, which is given the
+// region inside the border
+pub struct BorderedBox<T> {
+  region_index: usize,
+  border: Border,
+  pub inner: T,
+}
+
+impl<T> BorderedBox<T> {
+  // borders the region, creating the view inside the border from what's left
+  // of the region
+  pub fn new<F>(window: &mut Window, region_index: usize, create_inner: F) -> Self
+  where
+    F: FnOnce(usize) -> T,
+  {
+    let border = Border::new(BorderStyle::Single);
+    window.border(border.clone(), region_index);
+
+    BorderedBox {
+      region_index,
+      border,
+      inner: create_inner(region_index),
+    }
+  }
+
+  pub fn set_title(&mut self, title: Option<String>) {
+    self.border.title = title;
+  }
+
+  pub fn set_style(&mut self, style: BorderStyle) {
+    self.border.style = style;
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.border.fg = fg;
+  }
+}
+
+impl<T: View> View for BorderedBox<T> {
+  fn draw(&self, window: &mut Window) {
+    // the window draws the border itself, so it only needs to be kept up to
+    // date with any changes
+    window.set_border(self.border.clone(), self.region_index);
+    self.inner.draw(window);
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.inner.get_region_index()
+  }
+}
+
+impl<T: KeyEventHandleable> KeyEventHandleable for BorderedBox<T> {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    self.inner.handle_key_event(event);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyCode, KeyEventKind, KeyEventState, KeyModifiers};
+
+  use super::*;
+  use crate::views::text_block::TextBlock;
+  use crate::views::text_input::TextInput;
+
+  #[test]
+  fn it_draws_inside_border() {
+    let mut window = Window::new(8, 4);
+    let mut bordered = BorderedBox::new(&mut window, 0, TextBlock::new);
+    bordered.set_title(Some("Hi".to_owned()));
+    bordered.inner.set_lines(vec!["abc def".to_owned()]);
+
+    bordered.draw(&mut window);
+    window.display(&mut Vec::new());
+
+    assert_eq!(
+      window.snapshot(),
+      vec!["┌ Hi ──┐", "│abc   │", "│def   │", "└──────┘"]
+    );
+  }
+
+  #[test]
+  fn it_updates_border() {
+    let mut window = Window::new(6, 3);
+    let mut bordered = BorderedBox::new(&mut window, 0, TextBlock::new);
+    bordered.draw(&mut window);
+    window.display(&mut Vec::new());
+
+    assert_eq!(window.snapshot(), vec!["┌────┐", "│    │", "└────┘"]);
+
+    bordered.set_style(BorderStyle::Ascii);
+    bordered.set_title(Some("A".to_owned()));
+    bordered.draw(&mut window);
+    window.display(&mut Vec::new());
+
+    assert_eq!(window.snapshot(), vec!["+ A -+", "|    |", "+----+"]);
+  }
+
+  #[test]
+  fn it_passes_keys_to_inner_view() {
+    let mut window = Window::new(6, 3);
+    let mut bordered = BorderedBox::new(&mut window, 0, |region_index| {
+      TextInput::new(region_index, "ab")
+    });
+
+    bordered.handle_key_event(KeyEvent {
+      code: KeyCode::Char('c'),
+      modifiers: KeyModifiers::empty(),
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    });
+    bordered.draw(&mut window);
+    window.display(&mut Vec::new());
+
+    assert_eq!(bordered.inner.value(), "abc");
+    assert_eq!(window.snapshot(), vec!["┌────┐", "│abc │", "└────┘"]);
+  }
+}
@@ -1,128 +1,82 @@
-use crossterm::event::{KeyCode, KeyEvent};
 use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
 
-use super::view::{KeyEventHandleable, View};
+use super::view::View;
 use crate::framework::coord::Coord;
 use crate::framework::window::Window;
-use crate::models::progress::Progress;
+use crate::models::theme::Palette;
+use crate::models::word::GraphemeState;
 
-struct State {
-  // index of current char to be inputted
-  index: usize,
-  correct: Vec<Option<bool>>,
-}
-
-pub struct Line {
-  text: Vec<String>,
+// a single wrapped line of a line block, drawn at a row of its region
+pub struct Line<'a> {
+  // each grapheme along with how it was typed
+  graphemes: Vec<(&'a str, GraphemeState)>,
   region_index: usize,
-  line_index: usize,
-  state: State,
+  row: u16,
+  palette: Palette,
 }
 
-impl Line {
-  pub fn new(text: Vec<String>, region_index: usize, line_index: usize) -> Self {
-    let length = text.len();
+impl<'a> Line<'a> {
+  pub fn new(
+    graphemes: Vec<(&'a str, GraphemeState)>,
+    region_index: usize,
+    row: u16,
+    palette: Palette,
+  ) -> Self {
     Line {
-      text,
+      graphemes,
       region_index,
-      line_index,
-      state: State {
-        index: 0,
-        correct: vec![None; length],
-      },
-    }
-  }
-
-  pub fn reset_cursor(&self, window: &mut Window) {
-    window.set_cursor(
-      Coord {
-        row: self.line_index as u16,
-        col: self.state.index as u16,
-      },
-      self.region_index,
-    );
-  }
-
-  pub fn done(&self) -> bool {
-    let Progress {
-      correct,
-      incorrect: _,
-      total,
-    } = self.progress();
-    correct == total
-  }
-
-  pub fn progress(&self) -> Progress {
-    let correct = self
-      .state
-      .correct
-      .iter()
-      .filter(|&x| x == &Some(true))
-      .count();
-    let incorrect = self
-      .state
-      .correct
-      .iter()
-      .filter(|&x| x == &Some(false))
-      .count();
-    let total = self.text.len();
-    Progress {
-      correct,
-      incorrect,
-      total,
-    }
-  }
-
-  fn process_character(&mut self, c: char) {
-    if self.state.index < self.text.len() {
-      self.state.correct[self.state.index] = Some(c.to_string() == self.text[self.state.index]);
-      self.state.index += 1;
+      row,
+      palette,
     }
   }
 
-  fn process_backspace(&mut self) {
-    if self.state.index > 0 {
-      self.state.index -= 1;
-      if let Some(correct) = self.state.correct.get_mut(self.state.index) {
-        *correct = None;
-      }
-    }
+  pub fn width(&self) -> usize {
+    self.graphemes.iter().map(|(c, _)| c.width()).sum()
   }
 }
 
-impl View for Line {
+impl<'a> View for Line<'a> {
   fn draw(&self, window: &mut Window) {
-    for (i, c) in self.text.iter().enumerate() {
-      let fg = if c.contains(char::is_whitespace) {
-        Color::White
-      } else {
-        match self.state.correct[i] {
-          Some(true) => Color::Green,
-          Some(false) => Color::Red,
-          None => Color::White,
-        }
+    let mut col = 0;
+    for &(c, state) in &self.graphemes {
+      let color = match state {
+        GraphemeState::Untyped => None,
+        GraphemeState::Correct => Some(self.palette.correct),
+        GraphemeState::Incorrect => Some(self.palette.incorrect),
+        GraphemeState::Missed => Some(self.palette.missed),
+        GraphemeState::Extra => Some(self.palette.extra),
       };
 
-      let bg = if c.contains(char::is_whitespace) {
-        match self.state.correct[i] {
-          Some(true) => Color::Green,
-          Some(false) => Color::Red,
-          None => Color::Reset,
-        }
+      // whitespace is highlighted instead since it has no foreground
+      let (fg, bg) = if c.contains(char::is_whitespace) {
+        (self.palette.text, color.unwrap_or(Color::Reset))
       } else {
-        Color::Reset
+        (color.unwrap_or(self.palette.text), Color::Reset)
       };
 
+      window.draw(c, fg, bg, Coord { row: self.row, col }, self.region_index);
+      col += c.width() as u16;
+    }
+
+    // blank out the rest of the row so a scrolled line doesn't leave behind
+    // characters from whatever line was previously drawn there
+    let width = self.width();
+    let region_width = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw line -- invalid region.")
+      .width as usize;
+    if region_width > width {
       window.draw(
-        c,
-        fg,
-        bg,
+        &" ".repeat(region_width - width),
+        Color::Reset,
+        Color::Reset,
         Coord {
-          row: self.line_index as u16,
-          col: i as u16,
+          row: self.row,
+          col: width as u16,
         },
         self.region_index,
-      )
+      );
     }
   }
 
@@ -130,98 +84,3 @@ impl View for Line {
     self.region_index
   }
 }
-
-impl KeyEventHandleable for Line {
-  fn handle_key_event(&mut self, event: KeyEvent) {
-    match event.code {
-      KeyCode::Char(c) => self.process_character(c),
-      KeyCode::Backspace => self.process_backspace(),
-      _ => (),
-    }
-  }
-}
-
-#[cfg(test)]
-mod tests {
-  use unicode_segmentation::UnicodeSegmentation;
-
-  use super::*;
-
-  #[test]
-  fn it_processes_characters() {
-    let text = "text";
-    let chars = text.graphemes(true).map(String::from).collect();
-    let mut line = Line::new(chars, 0, 0);
-
-    line.process_character('t');
-    line.process_character('a');
-    line.process_character('x');
-    line.process_character('t');
-
-    assert_eq!(
-      line.state.correct,
-      vec![Some(true), Some(false), Some(true), Some(true)]
-    );
-    assert_eq!(line.state.index, line.text.len());
-
-    line.process_character('t');
-
-    assert_eq!(
-      line.state.correct,
-      vec![Some(true), Some(false), Some(true), Some(true)]
-    );
-    assert_eq!(line.state.index, line.text.len());
-  }
-
-  #[test]
-  fn it_processes_backspaces() {
-    let text = "text";
-    let chars = text.graphemes(true).map(String::from).collect();
-    let mut line = Line::new(chars, 0, 0);
-
-    line.process_backspace();
-
-    assert_eq!(line.state.correct, vec![None; line.text.len()]);
-    assert_eq!(line.state.index, 0);
-
-    line.process_character('t');
-    line.process_character('a');
-    line.process_character('x');
-    line.process_character('t');
-    line.process_character('t');
-    line.process_backspace();
-    line.process_backspace();
-
-    assert_eq!(
-      line.state.correct,
-      vec![Some(true), Some(false), None, None]
-    );
-    assert_eq!(line.state.index, line.text.len() - 2);
-  }
-
-  #[test]
-  fn it_checks_correctness() {
-    let text = "text";
-    let chars = text.graphemes(true).map(String::from).collect();
-    let mut line = Line::new(chars, 0, 0);
-
-    line.process_character('t');
-    line.process_character('e');
-    line.process_character('a');
-    line.process_character('t');
-    line.process_character('t');
-
-    assert!(!line.done());
-
-    line.process_backspace();
-    line.process_backspace();
-    line.process_character('x');
-    line.process_character('t');
-
-    assert!(line.done());
-
-    line.process_character('z');
-
-    assert!(line.done());
-  }
-}
@@ -1,92 +1,529 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use std::cmp;
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
 
 use super::line::Line;
 use super::view::{KeyEventHandleable, View};
+use crate::framework::coord::Coord;
+use crate::framework::frame_scheduler::Refresh;
 use crate::framework::window::Window;
+use crate::framework::wrap::{self, Position};
+use crate::models::correction_policy::CorrectionPolicy;
+use crate::models::input_mode::InputMode;
 use crate::models::progress::Progress;
+use crate::models::theme::Palette;
+use crate::models::word::{GraphemeState, Word};
+
+// number of lines kept visible above/below the current line when scrolling
+const CONTEXT_ABOVE: usize = 2;
+const CONTEXT_BELOW: usize = 3;
+
+// lines of text visible in the region, along with the index of the first one
+#[derive(Debug, PartialEq, Eq)]
+struct Viewport {
+  offset: usize,
+  rows: usize,
+}
+
+// limit on graphemes typed past the end of a word
+const MAX_EXTRA_GRAPHEMES: usize = 20;
 
 struct State {
-  // index of current line
-  index: usize,
+  // position of the next grapheme to be inputted
+  cursor: Position,
+  // range of displayed graphemes making up each wrapped line
+  lines: Vec<Range<Position>>,
+  // width the lines were last wrapped to
+  width: usize,
+  // keys typed, which unlike the graphemes' states includes mistakes that
+  // were later corrected
+  correct_keystrokes: usize,
+  incorrect_keystrokes: usize,
+}
+
+impl State {
+  fn new(width: usize) -> Self {
+    State {
+      cursor: Position {
+        word: 0,
+        grapheme: 0,
+      },
+      lines: vec![],
+      width,
+      correct_keystrokes: 0,
+      incorrect_keystrokes: 0,
+    }
+  }
 }
 
 pub struct LineBlock {
-  lines: Vec<Line>,
+  words: Vec<Word>,
   region_index: usize,
+  input_mode: InputMode,
+  policy: Box<dyn CorrectionPolicy>,
+  palette: Palette,
   state: State,
 }
 
 impl LineBlock {
-  pub fn new(text_lines: Vec<Vec<String>>, region_index: usize) -> Self {
-    LineBlock {
-      lines: text_lines
-        .into_iter()
-        .enumerate()
-        .map(|(line_index, text)| Line::new(text, region_index, line_index))
-        .collect(),
+  pub fn new(
+    words: Vec<String>,
+    region_index: usize,
+    input_mode: InputMode,
+    policy: Box<dyn CorrectionPolicy>,
+    palette: Palette,
+  ) -> Self {
+    let mut line_block = LineBlock {
+      words: vec![],
       region_index,
-      state: State { index: 0 },
-    }
+      input_mode,
+      policy,
+      palette,
+      state: State::new(usize::MAX),
+    };
+    // everything starts on one line until the line block is reflowed
+    line_block.reset(words);
+    line_block
+  }
+
+  // starts over with new text, keeping the current width
+  pub fn reset(&mut self, words: Vec<String>) {
+    let count = words.len();
+    self.words = words
+      .into_iter()
+      .enumerate()
+      .map(|(i, word)| {
+        if i + 1 < count {
+          Word::new(&format!("{} ", word))
+        } else {
+          Word::new(&word)
+        }
+      })
+      .collect();
+    self.state = State::new(self.state.width);
+    self.wrap(self.state.width);
+  }
+
+  // re-wraps the words to fit the current width of the region
+  pub fn reflow(&mut self, window: &Window) {
+    let width = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to reflow line block -- invalid region.")
+      .width as usize;
+    self.wrap(width);
   }
 
   pub fn reset_cursor(&self, window: &mut Window) {
-    if let Some(line) = self.lines.get(self.state.index) {
-      line.reset_cursor(window);
-    } else if let Some(line) = self.lines.get(self.state.index - 1) {
-      line.reset_cursor(window);
-    }
+    let line_index = self.current_line();
+    let viewport = self.viewport(self.region_height(window));
+    let line = &self.state.lines[line_index];
+    let col: usize = self
+      .positions(&(line.start..self.display_cursor()))
+      .iter()
+      .map(|&position| self.display_grapheme(position).0.width())
+      .sum();
+
+    window.set_cursor(
+      Coord {
+        row: (line_index - viewport.offset) as u16,
+        col: col as u16,
+      },
+      self.region_index,
+    );
   }
 
   pub fn done(&self) -> bool {
-    let Progress {
-      correct,
-      incorrect: _,
-      total,
-    } = self.progress();
-    correct == total
+    if self.policy.requires_correction() {
+      let Progress {
+        correct,
+        incorrect: _,
+        extra,
+        total,
+      } = self.progress();
+      correct == total && extra == 0
+    } else {
+      self.words.iter().all(|word| {
+        word
+          .states
+          .iter()
+          .all(|&state| state != GraphemeState::Untyped)
+      })
+    }
   }
 
   pub fn progress(&self) -> Progress {
-    self.lines.iter().map(|line| line.progress()).fold(
+    self.words.iter().map(|word| word.progress()).fold(
       Progress {
         correct: 0,
         incorrect: 0,
+        extra: 0,
         total: 0,
       },
       |acc, progress| acc + progress,
     )
   }
 
-  fn process_enter(&mut self) {
-    if let Some(line) = self.lines.get_mut(self.state.index) {
-      if line.done() {
-        self.state.index += 1;
+  // keystrokes counted towards accuracy so far
+  pub fn keystrokes(&self) -> usize {
+    self.state.correct_keystrokes + self.state.incorrect_keystrokes
+  }
+
+  // percentage of keystrokes which were correct
+  pub fn accuracy(&self) -> f32 {
+    let total = self.keystrokes();
+    if total == 0 {
+      100.0
+    } else {
+      (self.state.correct_keystrokes as f32) / (total as f32) * 100.0
+    }
+  }
+
+  // whether a mistake was left behind in a word that's been moved on from
+  pub fn has_uncorrected_mistake(&self) -> bool {
+    let passed = if self.done() {
+      self.words.len()
+    } else {
+      self.state.cursor.word
+    };
+    self.words[..passed].iter().any(Word::has_mistakes)
+  }
+
+  fn record_keystroke(&mut self, correct: bool) {
+    if correct {
+      self.state.correct_keystrokes += 1;
+    } else {
+      self.state.incorrect_keystrokes += 1;
+    }
+  }
+
+  fn wrap(&mut self, width: usize) {
+    let graphemes: Vec<Vec<&str>> = self
+      .words
+      .iter()
+      .map(|word| {
+        (0..word.display_len())
+          .map(|i| word.display_grapheme(i).0)
+          .collect()
+      })
+      .collect();
+    self.state.lines = wrap::wrap(&graphemes, width);
+    self.state.width = width;
+  }
+
+  // lines are made up of graphemes as they're displayed (including extra
+  // graphemes) so the cursor needs to be translated to match
+  fn display_cursor(&self) -> Position {
+    let cursor = self.state.cursor;
+    match self.words.get(cursor.word) {
+      Some(word) => Position {
+        word: cursor.word,
+        grapheme: word.display_index(cursor.grapheme),
+      },
+      None => cursor,
+    }
+  }
+
+  fn display_grapheme(&self, position: Position) -> (&str, GraphemeState) {
+    self.words[position.word].display_grapheme(position.grapheme)
+  }
+
+  // positions of every displayed grapheme within the range
+  fn positions(&self, range: &Range<Position>) -> Vec<Position> {
+    let words = range.start.word..cmp::min(range.end.word + 1, self.words.len());
+    words
+      .flat_map(|word| {
+        (0..self.words[word].display_len()).map(move |grapheme| Position { word, grapheme })
+      })
+      .filter(|position| range.contains(position))
+      .collect()
+  }
+
+  fn current_line(&self) -> usize {
+    let cursor = self.display_cursor();
+    self
+      .state
+      .lines
+      .iter()
+      .position(|line| line.contains(&cursor))
+      .unwrap_or(self.state.lines.len() - 1)
+  }
+
+  // the separator ending a line, if the line was broken between words
+  fn line_separator(&self, line_index: usize) -> Option<Position> {
+    let line = &self.state.lines[line_index];
+    if line_index + 1 < self.state.lines.len() && line.end.grapheme == 0 {
+      let word = line.end.word - 1;
+      Some(Position {
+        word,
+        grapheme: self.words[word].display_len() - 1,
+      })
+    } else {
+      None
+    }
+  }
+
+  // a line is done once everything but its trailing separator is correct (or
+  // just typed, if mistakes don't need correcting)
+  fn line_done(&self, line_index: usize) -> bool {
+    let separator = self.line_separator(line_index);
+    self
+      .positions(&self.state.lines[line_index])
+      .into_iter()
+      .filter(|&position| Some(position) != separator)
+      .all(|position| match self.display_grapheme(position).1 {
+        GraphemeState::Correct => true,
+        GraphemeState::Untyped => false,
+        _ => !self.policy.requires_correction(),
+      })
+  }
+
+  // whether the cursor is on the separator ending the current line, which (in
+  // line mode) can only be passed by pressing enter
+  fn at_line_end(&self) -> bool {
+    self.line_separator(self.current_line()) == Some(self.display_cursor())
+  }
+
+  fn region_height(&self, window: &Window) -> usize {
+    window
+      .region(self.region_index)
+      .expect("ERROR: Failed to get line block region -- invalid region.")
+      .height as usize
+  }
+
+  // picks which lines to show so that the current line keeps some context
+  // around it, scrolling one line at a time as lines are completed
+  fn viewport(&self, height: usize) -> Viewport {
+    let total = self.state.lines.len();
+    if total <= height {
+      return Viewport {
+        offset: 0,
+        rows: total,
+      };
+    }
+
+    // reserve the last row for the remaining lines indicator
+    let rows = height.saturating_sub(1);
+    let above = cmp::min(
+      CONTEXT_ABOVE,
+      rows.saturating_sub(1) * CONTEXT_ABOVE / (CONTEXT_ABOVE + CONTEXT_BELOW),
+    );
+    let offset = cmp::min(self.current_line().saturating_sub(above), total - rows);
+    Viewport { offset, rows }
+  }
+
+  // moves past the separator of the current word onto the next word
+  fn next_word(&mut self) {
+    let blocked = self.input_mode == InputMode::Line && self.at_line_end();
+    let cursor = &mut self.state.cursor;
+    let word = &mut self.words[cursor.word];
+    if !word.has_separator() || blocked {
+      return;
+    }
+
+    let separator = word.letter_count();
+    word.states[separator] = GraphemeState::Correct;
+    cursor.word += 1;
+    cursor.grapheme = 0;
+  }
+
+  fn process_character(&mut self, c: char) {
+    let cursor = self.state.cursor;
+    let word = match self.words.get_mut(cursor.word) {
+      Some(word) => word,
+      None => return,
+    };
+    let letters = word.letter_count();
+
+    if c.is_whitespace() {
+      // spaces at the start of a word are ignored, otherwise the rest of the
+      // word is skipped
+      if cursor.grapheme == 0 && letters > 0 {
+        return;
+      }
+      if cursor.grapheme < letters {
+        if !self.policy.advances_on_error() {
+          word.states[cursor.grapheme] = GraphemeState::Incorrect;
+          self.record_keystroke(false);
+          return;
+        }
+        if !self.policy.allows_leaving_incorrect_word() {
+          return;
+        }
+      }
+      if word.has_mistakes() && !self.policy.allows_leaving_incorrect_word() {
+        return;
+      }
+
+      let skipped = cursor.grapheme < letters;
+      let previous_word = cursor.word;
+      for state in &mut word.states[cursor.grapheme..letters] {
+        *state = GraphemeState::Missed;
+      }
+      self.state.cursor.grapheme = letters;
+      self.next_word();
+      if skipped || self.state.cursor.word != previous_word {
+        self.record_keystroke(!skipped);
+      }
+    } else if cursor.grapheme < letters {
+      let correct = c.to_string() == word.graphemes[cursor.grapheme];
+      word.states[cursor.grapheme] = if correct {
+        GraphemeState::Correct
+      } else {
+        GraphemeState::Incorrect
+      };
+      if correct || self.policy.advances_on_error() {
+        self.state.cursor.grapheme += 1;
+      }
+      self.record_keystroke(correct);
+    } else if self.policy.advances_on_error() && word.extra.len() < MAX_EXTRA_GRAPHEMES {
+      word.extra.push(c.to_string());
+      self.record_keystroke(false);
+      // extra graphemes take up space, which might change where lines break
+      self.wrap(self.state.width);
+    }
+  }
+
+  fn process_backspace(&mut self) {
+    if !self.policy.allows_backspace() {
+      return;
+    }
+
+    let cursor = &mut self.state.cursor;
+    let word = match self.words.get_mut(cursor.word) {
+      Some(word) => word,
+      None => return,
+    };
+
+    if cursor.grapheme == word.letter_count() && word.extra.pop().is_some() {
+      self.wrap(self.state.width);
+    } else if cursor.grapheme > 0 {
+      cursor.grapheme -= 1;
+      word.states[cursor.grapheme] = GraphemeState::Untyped;
+    } else if cursor.word > 0 && self.words[cursor.word - 1].has_mistakes() {
+      // previous words can only be revisited to correct them, returning to
+      // just after the last grapheme that was typed
+      cursor.word -= 1;
+      let word = &mut self.words[cursor.word];
+      let separator = word.letter_count();
+      word.states[separator] = GraphemeState::Untyped;
+
+      cursor.grapheme = separator;
+      while cursor.grapheme > 0 && word.states[cursor.grapheme - 1] == GraphemeState::Missed {
+        cursor.grapheme -= 1;
+        word.states[cursor.grapheme] = GraphemeState::Untyped;
+      }
+    }
+  }
+
+  // clears the current word, or the previous one if nothing has been typed in
+  // the current word yet
+  fn process_delete_word(&mut self) {
+    if !self.policy.allows_backspace() {
+      return;
+    }
+    if self.state.cursor.grapheme == 0 {
+      self.process_backspace();
+    }
+
+    let word_index = self.state.cursor.word;
+    while let Some(word) = self.words.get(word_index) {
+      let cursor = self.state.cursor;
+      if cursor.word != word_index || (cursor.grapheme == 0 && word.extra.is_empty()) {
+        break;
       }
+      self.process_backspace();
+    }
+  }
+
+  fn process_enter(&mut self) {
+    if self.input_mode == InputMode::Line
+      && self.at_line_end()
+      && self.line_done(self.current_line())
+    {
+      let cursor = &mut self.state.cursor;
+      let word = &mut self.words[cursor.word];
+      let separator = word.letter_count();
+      word.states[separator] = GraphemeState::Correct;
+      cursor.word += 1;
+      cursor.grapheme = 0;
     }
   }
 }
 
 impl View for LineBlock {
   fn draw(&self, window: &mut Window) {
-    for line in &self.lines {
-      line.draw(window);
+    let height = self.region_height(window);
+    let viewport = self.viewport(height);
+
+    for (row, line) in self
+      .state
+      .lines
+      .iter()
+      .skip(viewport.offset)
+      .take(viewport.rows)
+      .enumerate()
+    {
+      let graphemes = self
+        .positions(line)
+        .into_iter()
+        .map(|position| self.display_grapheme(position))
+        .collect();
+      Line::new(graphemes, self.region_index, row as u16, self.palette).draw(window);
+    }
+
+    let remaining = self.state.lines.len() - viewport.offset - viewport.rows;
+    if viewport.rows < height {
+      let indicator = match remaining {
+        0 => String::new(),
+        1 => "+1 more line".to_owned(),
+        n => format!("+{} more lines", n),
+      };
+      let width = window
+        .region(self.region_index)
+        .expect("ERROR: Failed to draw line block -- invalid region.")
+        .width as usize;
+      window.draw(
+        &format!("{:<width$}", indicator, width = width),
+        Color::DarkGrey,
+        Color::Reset,
+        Coord {
+          row: viewport.rows as u16,
+          col: 0,
+        },
+        self.region_index,
+      );
     }
   }
 
   fn get_region_index(&self) -> usize {
     self.region_index
   }
+
+  // the text only changes with keystrokes and reflows
+  fn refresh(&self) -> Refresh {
+    Refresh::OnChange
+  }
 }
 
 impl KeyEventHandleable for LineBlock {
   fn handle_key_event(&mut self, event: KeyEvent) {
+    let word_modifier = event
+      .modifiers
+      .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
     match event.code {
       KeyCode::Enter => self.process_enter(),
-      _ => {
-        if let Some(line) = self.lines.get_mut(self.state.index) {
-          line.handle_key_event(event)
-        }
+      // terminals report ctrl+backspace as either backspace or ctrl+h
+      KeyCode::Backspace if word_modifier => self.process_delete_word(),
+      KeyCode::Char('h') | KeyCode::Char('w') if event.modifiers == KeyModifiers::CONTROL => {
+        self.process_delete_word()
       }
+      // any other combination is a command rather than text
+      KeyCode::Char(c) if !word_modifier => self.process_character(c),
+      KeyCode::Backspace => self.process_backspace(),
+      _ => (),
     }
   }
 }
@@ -94,41 +531,672 @@ impl KeyEventHandleable for LineBlock {
 #[cfg(test)]
 mod tests {
   use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
-  use unicode_segmentation::UnicodeSegmentation;
 
   use super::*;
+  use crate::models::correction_policy::CorrectionPolicyKind;
+  use crate::models::theme::Theme;
+  use crate::models::word::GraphemeState::{Correct, Incorrect, Missed, Untyped};
   use crate::views::view::KeyEventHandleable;
 
   fn create_char_key_event(code: KeyCode) -> KeyEvent {
+    create_modified_key_event(code, KeyModifiers::empty())
+  }
+
+  fn create_modified_key_event(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
     KeyEvent {
       code,
-      modifiers: KeyModifiers::empty(),
+      modifiers,
       kind: KeyEventKind::Press,
       state: KeyEventState::empty(),
     }
   }
 
+  fn create_block(text: &str, width: usize) -> LineBlock {
+    create_block_with_mode(text, width, InputMode::Line)
+  }
+
+  fn create_block_with_mode(text: &str, width: usize, input_mode: InputMode) -> LineBlock {
+    create_block_with_policy(text, width, input_mode, CorrectionPolicyKind::Free)
+  }
+
+  fn create_block_with_policy(
+    text: &str,
+    width: usize,
+    input_mode: InputMode,
+    policy: CorrectionPolicyKind,
+  ) -> LineBlock {
+    let mut block = LineBlock::new(
+      text.split(' ').map(String::from).collect(),
+      0,
+      input_mode,
+      policy.policy(),
+      Theme::Default.palette(),
+    );
+    block.wrap(width);
+    block
+  }
+
+  fn line_words(block: &LineBlock) -> Vec<Range<usize>> {
+    block
+      .state
+      .lines
+      .iter()
+      .map(|line| line.start.word..line.end.word)
+      .collect()
+  }
+
+  fn states(block: &LineBlock) -> Vec<GraphemeState> {
+    block
+      .words
+      .iter()
+      .flat_map(|word| word.states.iter().copied())
+      .collect()
+  }
+
+  #[test]
+  fn it_processes_characters() {
+    let mut block = create_block("text", 80);
+
+    block.process_character('t');
+    block.process_character('a');
+    block.process_character('x');
+    block.process_character('t');
+
+    assert_eq!(states(&block), vec![Correct, Incorrect, Correct, Correct]);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 4
+      }
+    );
+
+    block.process_character('t');
+
+    assert_eq!(states(&block), vec![Correct, Incorrect, Correct, Correct]);
+    assert_eq!(block.words[0].extra, vec!["t"]);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 4
+      }
+    );
+  }
+
+  #[test]
+  fn it_processes_backspaces() {
+    let mut block = create_block("text", 80);
+
+    block.process_backspace();
+
+    assert_eq!(states(&block), vec![Untyped; 4]);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+
+    block.process_character('t');
+    block.process_character('a');
+    block.process_character('x');
+    block.process_character('t');
+    block.process_character('t');
+    block.process_backspace();
+    block.process_backspace();
+
+    assert_eq!(states(&block), vec![Correct, Incorrect, Correct, Untyped]);
+    assert!(block.words[0].extra.is_empty());
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 3
+      }
+    );
+  }
+
+  #[test]
+  fn it_checks_correctness() {
+    let mut block = create_block("text", 80);
+
+    block.process_character('t');
+    block.process_character('e');
+    block.process_character('a');
+    block.process_character('t');
+    block.process_character('t');
+
+    assert!(!block.done());
+
+    block.process_backspace();
+    block.process_backspace();
+    block.process_backspace();
+    block.process_character('x');
+    block.process_character('t');
+
+    assert!(block.done());
+
+    block.process_character('z');
+
+    assert!(!block.done());
+    assert_eq!(block.progress().extra, 1);
+  }
+
   #[test]
   fn it_goes_to_next_line() {
-    let text = "ab\ncd".to_owned();
-    let text_lines = text
-      .split('\n')
-      .map(|line| line.graphemes(true).map(String::from).collect())
-      .collect();
-    let mut block = LineBlock::new(text_lines, 0);
+    let mut block = create_block("ab cd", 3);
 
     block.handle_key_event(create_char_key_event(KeyCode::Char('a')));
     block.handle_key_event(create_char_key_event(KeyCode::Char('c')));
     block.handle_key_event(create_char_key_event(KeyCode::Backspace));
     block.handle_key_event(create_char_key_event(KeyCode::Char('b')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char(' ')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char('x')));
+    block.handle_key_event(create_char_key_event(KeyCode::Enter));
+
+    assert_eq!(block.current_line(), 0);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Backspace));
     block.handle_key_event(create_char_key_event(KeyCode::Enter));
 
-    assert_eq!(block.state.index, 1);
+    assert_eq!(block.current_line(), 1);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Backspace));
+
+    assert_eq!(block.current_line(), 1);
 
     block.handle_key_event(create_char_key_event(KeyCode::Char('c')));
     block.handle_key_event(create_char_key_event(KeyCode::Char('d')));
     block.handle_key_event(create_char_key_event(KeyCode::Enter));
 
-    assert_eq!(block.state.index, 2);
+    assert!(block.done());
+  }
+
+  #[test]
+  fn it_flows_to_next_line() {
+    let mut block = create_block_with_mode("ab cd", 3, InputMode::Continuous);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Char('a')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char('x')));
+    block.handle_key_event(create_char_key_event(KeyCode::Enter));
+
+    assert_eq!(block.current_line(), 0);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Char(' ')));
+
+    assert_eq!(block.current_line(), 1);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Backspace));
+    block.handle_key_event(create_char_key_event(KeyCode::Backspace));
+
+    assert_eq!(block.current_line(), 0);
+
+    block.handle_key_event(create_char_key_event(KeyCode::Char('b')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char(' ')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char('c')));
+    block.handle_key_event(create_char_key_event(KeyCode::Char('d')));
+
+    assert!(block.done());
+  }
+
+  #[test]
+  fn it_skips_words_with_space() {
+    let mut block = create_block_with_mode("abc de", 80, InputMode::Continuous);
+
+    block.process_character(' ');
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+
+    block.process_character('a');
+    block.process_character(' ');
+
+    assert_eq!(
+      states(&block),
+      vec![Correct, Missed, Missed, Correct, Untyped, Untyped]
+    );
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 0
+      }
+    );
+    assert_eq!(block.progress().incorrect, 2);
+
+    block.process_backspace();
+
+    assert_eq!(
+      states(&block),
+      vec![Correct, Untyped, Untyped, Untyped, Untyped, Untyped]
+    );
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 1
+      }
+    );
+  }
+
+  #[test]
+  fn it_tracks_extra_graphemes() {
+    let mut block = create_block_with_mode("ab cd", 5, InputMode::Continuous);
+
+    assert_eq!(line_words(&block), vec![0..2]);
+
+    for c in "abxy".chars() {
+      block.process_character(c);
+    }
+
+    assert_eq!(block.words[0].extra, vec!["x", "y"]);
+    assert_eq!(line_words(&block), vec![0..1, 1..2]);
+    assert_eq!(block.current_line(), 0);
+
+    block.process_character(' ');
+    block.process_backspace();
+    block.process_backspace();
+
+    assert_eq!(block.words[0].extra, vec!["x"]);
+    assert_eq!(line_words(&block), vec![0..1, 1..2]);
+
+    block.process_backspace();
+
+    assert!(block.words[0].extra.is_empty());
+    assert_eq!(line_words(&block), vec![0..2]);
+  }
+
+  #[test]
+  fn it_only_revisits_incorrect_words() {
+    let mut block = create_block_with_mode("ab cd ef", 80, InputMode::Continuous);
+
+    for c in "ab cx ".chars() {
+      block.process_character(c);
+    }
+    block.process_backspace();
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 2
+      }
+    );
+
+    block.process_backspace();
+    block.process_character('d');
+    block.process_character(' ');
+    block.process_backspace();
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 2,
+        grapheme: 0
+      }
+    );
+
+    for _ in 0..3 {
+      block.process_backspace();
+    }
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 2,
+        grapheme: 0
+      }
+    );
+  }
+
+  #[test]
+  fn it_deletes_words() {
+    let mut block = create_block_with_mode("ab cd ef", 80, InputMode::Continuous);
+
+    for c in "ax cdz".chars() {
+      block.process_character(c);
+    }
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Backspace,
+      KeyModifiers::CONTROL,
+    ));
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 0
+      }
+    );
+    assert!(block.words[1].extra.is_empty());
+
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Char('w'),
+      KeyModifiers::CONTROL,
+    ));
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+    assert_eq!(states(&block), vec![Untyped; 8]);
+
+    for c in "ab c".chars() {
+      block.process_character(c);
+    }
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Backspace,
+      KeyModifiers::ALT,
+    ));
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Backspace,
+      KeyModifiers::ALT,
+    ));
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 0
+      }
+    );
+  }
+
+  #[test]
+  fn it_ignores_commands() {
+    let mut block = create_block("ab", 80);
+
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Char('c'),
+      KeyModifiers::CONTROL,
+    ));
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Char('a'),
+      KeyModifiers::ALT,
+    ));
+
+    assert_eq!(states(&block), vec![Untyped; 2]);
+
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Char('A'),
+      KeyModifiers::SHIFT,
+    ));
+
+    assert_eq!(states(&block), vec![Incorrect, Untyped]);
+  }
+
+  #[test]
+  fn it_stops_on_error() {
+    let mut block = create_block_with_policy(
+      "ab c",
+      80,
+      InputMode::Continuous,
+      CorrectionPolicyKind::StopOnError,
+    );
+
+    block.process_character('x');
+
+    assert_eq!(states(&block)[0], Incorrect);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+
+    block.process_character('a');
+    block.process_character(' ');
+    block.process_character('b');
+    block.process_character('y');
+    block.process_character(' ');
+
+    assert_eq!(states(&block), vec![Correct, Correct, Correct, Untyped]);
+    assert!(block.words[0].extra.is_empty());
+
+    block.process_character('c');
+
+    assert!(block.done());
+  }
+
+  #[test]
+  fn it_prevents_backspace() {
+    let mut block = create_block_with_policy(
+      "ab c",
+      80,
+      InputMode::Continuous,
+      CorrectionPolicyKind::NoBackspace,
+    );
+
+    block.process_character('x');
+    block.process_backspace();
+    block.handle_key_event(create_modified_key_event(
+      KeyCode::Char('w'),
+      KeyModifiers::CONTROL,
+    ));
+
+    assert_eq!(states(&block), vec![Incorrect, Untyped, Untyped, Untyped]);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 1
+      }
+    );
+
+    block.process_character(' ');
+    block.process_character('c');
+
+    assert_eq!(states(&block), vec![Incorrect, Missed, Correct, Correct]);
+    assert!(block.done());
+  }
+
+  #[test]
+  fn it_stops_on_word() {
+    let mut block = create_block_with_policy(
+      "ab c",
+      80,
+      InputMode::Continuous,
+      CorrectionPolicyKind::StopOnWord,
+    );
+
+    block.process_character('a');
+    block.process_character(' ');
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 1
+      }
+    );
+
+    block.process_character('x');
+    block.process_character(' ');
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 2
+      }
+    );
+
+    block.process_backspace();
+    block.process_character('b');
+    block.process_character(' ');
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 0
+      }
+    );
+  }
+
+  #[test]
+  fn it_tracks_accuracy() {
+    let mut block = create_block_with_mode("abc def gh", 80, InputMode::Continuous);
+
+    assert_eq!(block.accuracy(), 100.0);
+
+    for c in "ax".chars() {
+      block.process_character(c);
+    }
+    block.process_backspace();
+    for c in "bc ".chars() {
+      block.process_character(c);
+    }
+
+    // corrected mistakes still count against accuracy
+    assert_eq!(block.accuracy(), 80.0);
+    assert!(!block.has_uncorrected_mistake());
+
+    for c in "de ".chars() {
+      block.process_character(c);
+    }
+
+    assert_eq!(block.accuracy(), 75.0);
+    assert!(block.has_uncorrected_mistake());
+  }
+
+  #[test]
+  fn it_resets() {
+    let mut block = create_block("ab cd", 3);
+    for c in "ax ".chars() {
+      block.process_character(c);
+    }
+
+    block.reset(vec!["ef".to_owned(), "gh".to_owned()]);
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+    assert_eq!(states(&block), vec![GraphemeState::Untyped; 5]);
+    assert_eq!(line_words(&block), vec![0..1, 1..2]);
+    assert_eq!(block.accuracy(), 100.0);
+  }
+
+  #[test]
+  fn it_reflows_preserving_progress() {
+    let mut block = create_block("ab cd ef", 6);
+
+    for c in "ab cd".chars() {
+      block.process_character(c);
+    }
+
+    assert_eq!(line_words(&block), vec![0..2, 2..3]);
+    assert_eq!(block.current_line(), 0);
+
+    block.wrap(3);
+
+    assert_eq!(line_words(&block), vec![0..1, 1..2, 2..3]);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 1,
+        grapheme: 2
+      }
+    );
+    assert_eq!(block.current_line(), 1);
+    assert_eq!(block.progress().correct, 5);
+
+    block.process_enter();
+    for c in "ef".chars() {
+      block.process_character(c);
+    }
+
+    assert!(block.done());
+  }
+
+  #[test]
+  fn it_revisits_incomplete_lines_after_reflow() {
+    let mut block = create_block("ab cd", 80);
+
+    block.process_character('x');
+    block.process_character('b');
+    block.process_character(' ');
+    block.wrap(3);
+
+    assert_eq!(block.current_line(), 1);
+
+    block.process_backspace();
+    block.process_backspace();
+    block.process_backspace();
+
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 0
+      }
+    );
+    assert_eq!(states(&block), vec![Untyped; 5]);
+  }
+
+  #[test]
+  fn it_flows_through_broken_words() {
+    let mut block = create_block("abcdef g", 4);
+
+    assert_eq!(line_words(&block), vec![0..0, 0..2]);
+
+    for c in "abcde".chars() {
+      block.process_character(c);
+    }
+
+    assert_eq!(block.current_line(), 1);
+
+    block.process_backspace();
+    block.process_backspace();
+
+    assert_eq!(block.current_line(), 0);
+    assert_eq!(
+      block.state.cursor,
+      Position {
+        word: 0,
+        grapheme: 3
+      }
+    );
+  }
+
+  #[test]
+  fn it_scrolls_to_current_line() {
+    let mut block = create_block("0 1 2 3 4 5 6 7 8 9", 2);
+
+    assert_eq!(
+      block.viewport(20),
+      Viewport {
+        offset: 0,
+        rows: 10
+      }
+    );
+    assert_eq!(block.viewport(7), Viewport { offset: 0, rows: 6 });
+
+    block.state.cursor.word = 2;
+    assert_eq!(block.viewport(7), Viewport { offset: 0, rows: 6 });
+
+    block.state.cursor.word = 3;
+    assert_eq!(block.viewport(7), Viewport { offset: 1, rows: 6 });
+
+    block.state.cursor.word = 9;
+    assert_eq!(block.viewport(7), Viewport { offset: 4, rows: 6 });
+
+    block.state.cursor.grapheme = 1;
+    assert_eq!(block.viewport(7), Viewport { offset: 4, rows: 6 });
   }
 }
@@ -0,0 +1,193 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+
+use super::view::{KeyEventHandleable, View};
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+use crate::models::theme::Palette;
+
+// width given to labels so that values line up
+const LABEL_WIDTH: usize = 20;
+
+pub struct MenuItem {
+  pub label: String,
+  // current value of a setting, which can be changed with the left/right keys
+  pub value: Option<String>,
+}
+
+// a list of items, one of which is selected using the up/down keys
+pub struct MenuList {
+  region_index: usize,
+  palette: Palette,
+  items: Vec<MenuItem>,
+  selected: usize,
+}
+
+impl MenuList {
+  pub fn new(region_index: usize, palette: Palette) -> Self {
+    MenuList {
+      region_index,
+      palette,
+      items: vec![],
+      selected: 0,
+    }
+  }
+
+  // replaces the items, keeping the selection where possible
+  pub fn set_items(&mut self, items: Vec<MenuItem>) {
+    self.items = items;
+    self.selected = self.selected.min(self.items.len().saturating_sub(1));
+  }
+
+  pub fn set_palette(&mut self, palette: Palette) {
+    self.palette = palette;
+  }
+
+  pub fn selected(&self) -> usize {
+    self.selected
+  }
+}
+
+impl View for MenuList {
+  fn draw(&self, window: &mut Window) {
+    window.clear_region(self.region_index);
+    let height = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw menu -- invalid region.")
+      .height as usize;
+
+    // keep the selected item in view
+    let offset = (self.selected + 1).saturating_sub(height);
+    for (row, (index, item)) in self
+      .items
+      .iter()
+      .enumerate()
+      .skip(offset)
+      .take(height)
+      .enumerate()
+    {
+      let s = match &item.value {
+        Some(value) => format!("{:width$}< {} >", item.label, value, width = LABEL_WIDTH),
+        None => item.label.clone(),
+      };
+      let (fg, bg) = if index == self.selected {
+        (Color::Black, self.palette.highlight)
+      } else {
+        (self.palette.text, Color::Reset)
+      };
+      window.draw(
+        &s,
+        fg,
+        bg,
+        Coord {
+          row: row as u16,
+          col: 0,
+        },
+        self.region_index,
+      );
+    }
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
+
+impl KeyEventHandleable for MenuList {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    if self.items.is_empty() {
+      return;
+    }
+    match event.code {
+      KeyCode::Up => self.selected = (self.selected + self.items.len() - 1) % self.items.len(),
+      KeyCode::Down => self.selected = (self.selected + 1) % self.items.len(),
+      KeyCode::Home => self.selected = 0,
+      KeyCode::End => self.selected = self.items.len() - 1,
+      _ => (),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+
+  use super::*;
+  use crate::models::theme::Theme;
+
+  fn create_key_event(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+      code,
+      modifiers: KeyModifiers::empty(),
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    }
+  }
+
+  fn create_list(count: usize) -> MenuList {
+    let mut list = MenuList::new(0, Theme::Default.palette());
+    list.set_items(
+      (0..count)
+        .map(|i| MenuItem {
+          label: i.to_string(),
+          value: None,
+        })
+        .collect(),
+    );
+    list
+  }
+
+  #[test]
+  fn it_moves_selection() {
+    let mut list = create_list(3);
+
+    list.handle_key_event(create_key_event(KeyCode::Down));
+    list.handle_key_event(create_key_event(KeyCode::Down));
+    assert_eq!(list.selected(), 2);
+
+    list.handle_key_event(create_key_event(KeyCode::Down));
+    assert_eq!(list.selected(), 0);
+
+    list.handle_key_event(create_key_event(KeyCode::Up));
+    assert_eq!(list.selected(), 2);
+
+    list.set_items(vec![]);
+    assert_eq!(list.selected(), 0);
+  }
+
+  #[test]
+  fn it_wraps_selection_around() {
+    let mut list = create_list(4);
+
+    list.handle_key_event(create_key_event(KeyCode::Up));
+    assert_eq!(list.selected(), 3);
+
+    list.handle_key_event(create_key_event(KeyCode::Up));
+    assert_eq!(list.selected(), 2);
+
+    list.handle_key_event(create_key_event(KeyCode::Home));
+    assert_eq!(list.selected(), 0);
+
+    list.handle_key_event(create_key_event(KeyCode::End));
+    list.handle_key_event(create_key_event(KeyCode::Down));
+    assert_eq!(list.selected(), 0);
+
+    let mut empty = create_list(0);
+    empty.handle_key_event(create_key_event(KeyCode::Up));
+    assert_eq!(empty.selected(), 0);
+  }
+
+  #[test]
+  fn it_scrolls_to_selection() {
+    let mut window = Window::new(3, 2);
+    let mut list = create_list(4);
+
+    list.handle_key_event(create_key_event(KeyCode::Up));
+    list.draw(&mut window);
+    assert_eq!(window.snapshot(), vec!["2  ", "3  "]);
+
+    list.handle_key_event(create_key_event(KeyCode::Down));
+    list.draw(&mut window);
+    assert_eq!(window.snapshot(), vec!["0  ", "1  "]);
+  }
+}
@@ -0,0 +1,332 @@
+use std::cmp;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
+
+use super::border::draw_frame;
+use super::text_input::TextInput;
+use super::view::{KeyEventHandleable, View};
+use crate::framework::border::BorderStyle;
+use crate::framework::coord::Coord;
+use crate::framework::rect::Rect;
+use crate::framework::window::Window;
+use crate::framework::wrap::wrap_text;
+
+// widest a dialog gets, so that messages wrap onto multiple lines
+const MAX_WIDTH: usize = 60;
+// room taken up by the border and a column of space on either side
+const HORIZONTAL_PADDING: usize = 4;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ModalResponse {
+  Pending,
+  // the index of the button that was pressed
+  Confirmed(usize),
+  Dismissed,
+}
+
+// a dialog drawn over the middle of a region, with a message, an optional
+// text input and a row of buttons chosen with the left/right keys
+pub struct Modal {
+  region_index: usize,
+  title: String,
+  message: String,
+  input: Option<TextInput>,
+  buttons: Vec<String>,
+  selected: usize,
+  response: ModalResponse,
+  style: BorderStyle,
+  fg: Color,
+}
+
+impl Modal {
+  pub fn new(region_index: usize, title: &str, message: &str, buttons: &[&str]) -> Self {
+    Modal {
+      region_index,
+      title: title.to_owned(),
+      message: message.to_owned(),
+      input: None,
+      buttons: buttons.iter().map(|&button| button.to_owned()).collect(),
+      selected: 0,
+      response: ModalResponse::Pending,
+      style: BorderStyle::Single,
+      fg: Color::White,
+    }
+  }
+
+  // a dialog asking for a line of text, confirmed with the first button
+  pub fn prompt(region_index: usize, title: &str, message: &str, value: &str) -> Self {
+    let mut modal = Modal::new(region_index, title, message, &["OK", "Cancel"]);
+    modal.input = Some(TextInput::new(region_index, value));
+    modal
+  }
+
+  pub fn response(&self) -> ModalResponse {
+    self.response
+  }
+
+  pub fn input_value(&self) -> Option<String> {
+    self.input.as_ref().map(TextInput::value)
+  }
+
+  pub fn set_style(&mut self, style: BorderStyle) {
+    self.style = style;
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.fg = fg;
+    if let Some(input) = &mut self.input {
+      input.set_color(fg);
+    }
+  }
+
+  // shows the cursor in the input, if there is one
+  pub fn reset_cursor(&self, window: &mut Window) {
+    let rect = self.rect(window);
+    match &self.input {
+      Some(input) => {
+        let (coord, width) = self.input_position(rect);
+        input.reset_cursor_at(window, self.region_index, coord, width);
+        window.set_cursor_visible(true);
+      }
+      None => window.set_cursor_visible(false),
+    }
+  }
+
+  fn buttons_text(&self) -> Vec<String> {
+    self
+      .buttons
+      .iter()
+      .map(|button| format!("[ {} ]", button))
+      .collect()
+  }
+
+  fn message_lines(&self, width: usize) -> Vec<String> {
+    wrap_text(&self.message, width)
+  }
+
+  // where the dialog sits within the region
+  fn rect(&self, window: &Window) -> Rect {
+    let region = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw modal -- invalid region.");
+
+    let buttons_width = self.buttons_text().join(" ").width();
+    let content_width = cmp::max(
+      cmp::max(self.message.width(), buttons_width),
+      self.title.width() + 2,
+    );
+    let width = cmp::min(
+      cmp::min(content_width + HORIZONTAL_PADDING, MAX_WIDTH),
+      region.width as usize,
+    );
+    let lines = self
+      .message_lines(width.saturating_sub(HORIZONTAL_PADDING))
+      .len();
+    // border, message, input, a blank line and the buttons
+    let input_height = if self.input.is_some() { 2 } else { 0 };
+    let height = cmp::min(lines + input_height + 4, region.height as usize);
+
+    Rect {
+      coord: Coord {
+        row: (region.height - height as u16) / 2,
+        col: (region.width - width as u16) / 2,
+      },
+      width: width as u16,
+      height: height as u16,
+    }
+  }
+
+  // where the input goes, below the message, and how wide it is
+  fn input_position(&self, rect: Rect) -> (Coord, usize) {
+    let width = (rect.width as usize).saturating_sub(HORIZONTAL_PADDING);
+    let lines = self.message_lines(width).len() as u16;
+    (
+      rect.coord
+        + Coord {
+          row: 2 + lines,
+          col: 2,
+        },
+      width,
+    )
+  }
+}
+
+impl View for Modal {
+  fn draw(&self, window: &mut Window) {
+    let rect = self.rect(window);
+    let inner_width = (rect.width as usize).saturating_sub(HORIZONTAL_PADDING);
+
+    // blank out whatever is underneath
+    for row in 0..rect.height {
+      window.draw(
+        &" ".repeat(rect.width as usize),
+        self.fg,
+        Color::Reset,
+        rect.coord + Coord { row, col: 0 },
+        self.region_index,
+      );
+    }
+    draw_frame(
+      window,
+      self.region_index,
+      rect,
+      self.style.chars(),
+      Some(&self.title),
+      self.fg,
+    );
+
+    let lines = self.message_lines(inner_width);
+    for (row, line) in lines.iter().enumerate() {
+      window.draw(
+        line,
+        self.fg,
+        Color::Reset,
+        rect.coord
+          + Coord {
+            row: row as u16 + 1,
+            col: 2,
+          },
+        self.region_index,
+      );
+    }
+
+    if let Some(input) = &self.input {
+      let (coord, width) = self.input_position(rect);
+      input.draw_at(window, self.region_index, coord, width);
+    }
+
+    let buttons = self.buttons_text();
+    let buttons_width = buttons.join(" ").width() as u16;
+    let mut col = (rect.width.saturating_sub(buttons_width)) / 2;
+    for (i, button) in buttons.iter().enumerate() {
+      let (fg, bg) = if i == self.selected {
+        (Color::Black, self.fg)
+      } else {
+        (self.fg, Color::Reset)
+      };
+      window.draw(
+        button,
+        fg,
+        bg,
+        rect.coord
+          + Coord {
+            row: rect.height.saturating_sub(2),
+            col,
+          },
+        self.region_index,
+      );
+      col += button.width() as u16 + 1;
+    }
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
+
+impl KeyEventHandleable for Modal {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    let count = self.buttons.len();
+    match event.code {
+      KeyCode::Enter => self.response = ModalResponse::Confirmed(self.selected),
+      KeyCode::Esc => self.response = ModalResponse::Dismissed,
+      KeyCode::Tab => self.selected = (self.selected + 1) % count,
+      KeyCode::BackTab => self.selected = (self.selected + count - 1) % count,
+      _ => match &mut self.input {
+        // the left/right keys move the input's cursor instead
+        Some(input) => input.handle_key_event(event),
+        None => match event.code {
+          KeyCode::Left => self.selected = (self.selected + count - 1) % count,
+          KeyCode::Right => self.selected = (self.selected + 1) % count,
+          _ => (),
+        },
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+
+  use super::*;
+
+  fn create_key_event(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+      code,
+      modifiers: KeyModifiers::empty(),
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    }
+  }
+
+  #[test]
+  fn it_draws_centered() {
+    let mut window = Window::new(20, 7);
+    let modal = Modal::new(0, "Hi", "Hello", &["OK"]);
+
+    modal.draw(&mut window);
+
+    assert_eq!(
+      window.snapshot(),
+      vec![
+        "                    ",
+        "     ┌ Hi ────┐     ",
+        "     │ Hello  │     ",
+        "     │        │     ",
+        "     │ [ OK ] │     ",
+        "     └────────┘     ",
+        "                    ",
+      ]
+    );
+  }
+
+  #[test]
+  fn it_responds_to_keys() {
+    let mut modal = Modal::prompt(0, "Name", "Enter a name", "ab");
+
+    modal.handle_key_event(create_key_event(KeyCode::Char('c')));
+    modal.handle_key_event(create_key_event(KeyCode::Tab));
+    assert_eq!(modal.response(), ModalResponse::Pending);
+
+    modal.handle_key_event(create_key_event(KeyCode::Enter));
+    assert_eq!(modal.response(), ModalResponse::Confirmed(1));
+    assert_eq!(modal.input_value(), Some("abc".to_owned()));
+
+    modal.handle_key_event(create_key_event(KeyCode::Esc));
+    assert_eq!(modal.response(), ModalResponse::Dismissed);
+  }
+
+  #[test]
+  fn it_confirms_chosen_button() {
+    let mut modal = Modal::new(0, "Quit", "Are you sure?", &["Yes", "No"]);
+
+    modal.handle_key_event(create_key_event(KeyCode::Right));
+    modal.handle_key_event(create_key_event(KeyCode::Right));
+    modal.handle_key_event(create_key_event(KeyCode::Left));
+    assert_eq!(modal.response(), ModalResponse::Pending);
+
+    modal.handle_key_event(create_key_event(KeyCode::Enter));
+    assert_eq!(modal.response(), ModalResponse::Confirmed(1));
+    assert_eq!(modal.input_value(), None);
+  }
+
+  #[test]
+  fn it_cancels_prompts() {
+    let mut modal = Modal::prompt(0, "Name", "Enter a name", "ab");
+
+    // the arrow keys edit the input rather than choosing a button
+    modal.handle_key_event(create_key_event(KeyCode::Left));
+    modal.handle_key_event(create_key_event(KeyCode::Char('x')));
+    modal.handle_key_event(create_key_event(KeyCode::BackTab));
+    modal.handle_key_event(create_key_event(KeyCode::Enter));
+    assert_eq!(modal.response(), ModalResponse::Confirmed(1));
+    assert_eq!(modal.input_value(), Some("axb".to_owned()));
+
+    let mut modal = Modal::prompt(0, "Name", "Enter a name", "ab");
+    modal.handle_key_event(create_key_event(KeyCode::Esc));
+    assert_eq!(modal.response(), ModalResponse::Dismissed);
+  }
+}
@@ -0,0 +1,47 @@
+use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
+
+use super::view::View;
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+
+// covers a region with a message centered in it, hiding whatever is underneath
+pub struct Overlay {
+  region_index: usize,
+  message: String,
+}
+
+impl Overlay {
+  pub fn new(region_index: usize, message: &str) -> Self {
+    Overlay {
+      region_index,
+      message: message.to_owned(),
+    }
+  }
+}
+
+impl View for Overlay {
+  fn draw(&self, window: &mut Window) {
+    let region = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw overlay -- invalid region.");
+    let col = (region.width as usize).saturating_sub(self.message.width()) / 2;
+    let row = region.height / 2;
+
+    window.clear_region(self.region_index);
+    window.draw(
+      &self.message,
+      Color::Black,
+      Color::White,
+      Coord {
+        row,
+        col: col as u16,
+      },
+      self.region_index,
+    );
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
@@ -0,0 +1,72 @@
+use crossterm::event::KeyEvent;
+use crossterm::style::Color;
+
+use super::bordered_box::BorderedBox;
+use super::view::{KeyEventHandleable, View};
+use crate::framework::border::BorderStyle;
+use crate::framework::coord::Coord;
+use crate::framework::split::HorizontalSplitKind;
+use crate::framework::window::Window;
+
+// the content of a screen in a titled box, with a line of key hints under it
+pub struct Page<T> {
+  pub content: BorderedBox<T>,
+  hint_region: usize,
+  hint: String,
+}
+
+impl<T> Page<T> {
+  pub fn new<F>(
+    window: &mut Window,
+    region_index: usize,
+    title: &str,
+    hint: &str,
+    create_content: F,
+  ) -> Self
+  where
+    F: FnOnce(usize) -> T,
+  {
+    let (box_region, hint_region) =
+      window.horizontal_split(HorizontalSplitKind::CellsInBottom(1), region_index);
+    let mut content = BorderedBox::new(window, box_region, create_content);
+    content.set_title(Some(title.to_owned()));
+
+    Page {
+      content,
+      hint_region,
+      hint: hint.to_owned(),
+    }
+  }
+
+  pub fn set_style(&mut self, style: BorderStyle) {
+    self.content.set_style(style);
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.content.set_color(fg);
+  }
+}
+
+impl<T: View> View for Page<T> {
+  fn draw(&self, window: &mut Window) {
+    self.content.draw(window);
+    window.clear_region(self.hint_region);
+    window.draw(
+      &self.hint,
+      Color::DarkGrey,
+      Color::Reset,
+      Coord { row: 0, col: 0 },
+      self.hint_region,
+    );
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.content.get_region_index()
+  }
+}
+
+impl<T: KeyEventHandleable> KeyEventHandleable for Page<T> {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    self.content.handle_key_event(event);
+  }
+}
@@ -1,29 +1,42 @@
-use crossterm::style::Color;
+use std::time::Duration;
 
 use super::view::View;
 use crate::framework::coord::Coord;
+use crate::framework::frame_scheduler::Refresh;
 use crate::framework::window::Window;
 use crate::models::progress::Progress;
+use crate::models::theme::Palette;
 
 const BAR_SYMBOL: &str = "░";
 
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+const EMPTY_PROGRESS: Progress = Progress {
+  correct: 0,
+  incorrect: 0,
+  extra: 0,
+  total: 0,
+};
+
 pub struct ProgressBar {
   region_index: usize,
+  palette: Palette,
   progress: Progress,
 }
 
 impl ProgressBar {
-  pub fn new(region_index: usize) -> Self {
+  pub fn new(region_index: usize, palette: Palette) -> Self {
     ProgressBar {
       region_index,
-      progress: Progress {
-        correct: 0,
-        incorrect: 0,
-        total: 0,
-      },
+      palette,
+      progress: EMPTY_PROGRESS,
     }
   }
 
+  pub fn reset(&mut self) {
+    self.progress = EMPTY_PROGRESS;
+  }
+
   pub fn set_progress(&mut self, progress: Progress) {
     assert!(
       progress.correct + progress.incorrect <= progress.total,
@@ -60,22 +73,22 @@ impl View for ProgressBar {
     window.clear_region(self.region_index);
     window.draw(
       &total_string,
-      Color::White,
-      Color::White,
+      self.palette.text,
+      self.palette.text,
       Coord { row: 0, col: 0 },
       self.region_index,
     );
     window.draw(
       &correct_string,
-      Color::Green,
-      Color::Green,
+      self.palette.correct,
+      self.palette.correct,
       Coord { row: 0, col: 0 },
       self.region_index,
     );
     window.draw(
       &incorrect_string,
-      Color::Red,
-      Color::Red,
+      self.palette.incorrect,
+      self.palette.incorrect,
       Coord {
         row: 0,
         col: correct_width as u16,
@@ -87,4 +100,8 @@ impl View for ProgressBar {
   fn get_region_index(&self) -> usize {
     self.region_index
   }
+
+  fn refresh(&self) -> Refresh {
+    Refresh::Every(REFRESH_INTERVAL)
+  }
 }
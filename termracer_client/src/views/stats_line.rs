@@ -1,44 +1,103 @@
+use std::time::Duration;
+
 use crossterm::style::Color;
 
 use super::view::View;
 use crate::framework::coord::Coord;
+use crate::framework::frame_scheduler::Refresh;
 use crate::framework::window::Window;
+use crate::models::theme::Palette;
+
+// stats jumping around with every keystroke are hard to read
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
 
 macro_rules! STATS_LINE_FORMAT_STRING {
   () => {
-    "WPM: {}"
+    "WPM: {}  ACC: {}%"
+  };
+}
+
+macro_rules! TIME_LEFT_FORMAT_STRING {
+  () => {
+    "  TIME: {}s"
   };
 }
 
 struct State {
   wpm: f32,
+  accuracy: f32,
+  // seconds left in a timed test
+  time_left: Option<u64>,
+  // shown in place of the stats, e.g. while waiting for the test to start
+  prompt: Option<String>,
+}
+
+impl State {
+  fn new() -> Self {
+    State {
+      wpm: 0.0,
+      accuracy: 100.0,
+      time_left: None,
+      prompt: None,
+    }
+  }
 }
 
 pub struct StatsLine {
   region_index: usize,
+  palette: Palette,
   state: State,
 }
 
 impl StatsLine {
-  pub fn new(region_index: usize) -> Self {
+  pub fn new(region_index: usize, palette: Palette) -> Self {
     StatsLine {
       region_index,
-      state: State { wpm: 0.0 },
+      palette,
+      state: State::new(),
     }
   }
 
+  pub fn reset(&mut self) {
+    self.state = State::new();
+  }
+
   pub fn set_wpm(&mut self, wpm: f32) {
     self.state.wpm = wpm;
   }
+
+  pub fn set_accuracy(&mut self, accuracy: f32) {
+    self.state.accuracy = accuracy;
+  }
+
+  pub fn set_time_left(&mut self, time_left: Option<u64>) {
+    self.state.time_left = time_left;
+  }
+
+  pub fn set_prompt(&mut self, prompt: Option<String>) {
+    self.state.prompt = prompt;
+  }
 }
 
 impl View for StatsLine {
   fn draw(&self, window: &mut Window) {
-    let s = format!(STATS_LINE_FORMAT_STRING!(), self.state.wpm as u32);
+    let s = match &self.state.prompt {
+      Some(prompt) => prompt.clone(),
+      None => {
+        let mut s = format!(
+          STATS_LINE_FORMAT_STRING!(),
+          self.state.wpm as u32, self.state.accuracy as u32
+        );
+        if let Some(time_left) = self.state.time_left {
+          s.push_str(&format!(TIME_LEFT_FORMAT_STRING!(), time_left));
+        }
+        s
+      }
+    };
     window.clear_region(self.region_index);
     window.draw(
       &s,
-      Color::White,
+      self.palette.text,
       Color::Reset,
       Coord { row: 0, col: 0 },
       self.region_index,
@@ -48,4 +107,8 @@ impl View for StatsLine {
   fn get_region_index(&self) -> usize {
     self.region_index
   }
+
+  fn refresh(&self) -> Refresh {
+    Refresh::Every(REFRESH_INTERVAL)
+  }
 }
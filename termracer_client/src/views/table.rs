@@ -0,0 +1,201 @@
+use std::cmp;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::style::Color;
+use unicode_width::UnicodeWidthStr;
+
+use super::view::{KeyEventHandleable, View};
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+
+// space between columns
+const COLUMN_GAP: usize = 2;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Alignment {
+  Left,
+  Right,
+}
+
+pub struct Column {
+  pub title: String,
+  pub alignment: Alignment,
+}
+
+// rows of cells lined up under column headers, scrolled with the up/down keys
+pub struct Table {
+  region_index: usize,
+  columns: Vec<Column>,
+  rows: Vec<Vec<String>>,
+  // index of the first row shown
+  offset: usize,
+  fg: Color,
+}
+
+impl Table {
+  pub fn new(region_index: usize, columns: Vec<Column>) -> Self {
+    Table {
+      region_index,
+      columns,
+      rows: vec![],
+      offset: 0,
+      fg: Color::White,
+    }
+  }
+
+  pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+    self.rows = rows;
+    self.offset = cmp::min(self.offset, self.rows.len().saturating_sub(1));
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.fg = fg;
+  }
+
+  // each column is as wide as its widest cell
+  fn column_widths(&self) -> Vec<usize> {
+    self
+      .columns
+      .iter()
+      .enumerate()
+      .map(|(i, column)| {
+        self
+          .rows
+          .iter()
+          .filter_map(|row| row.get(i))
+          .map(|cell| cell.width())
+          .fold(column.title.width(), cmp::max)
+      })
+      .collect()
+  }
+
+  fn format_row<'a, I>(&self, cells: I, widths: &[usize]) -> String
+  where
+    I: Iterator<Item = &'a str>,
+  {
+    let mut s = String::new();
+    for ((cell, column), &width) in cells.zip(&self.columns).zip(widths) {
+      if !s.is_empty() {
+        s.push_str(&" ".repeat(COLUMN_GAP));
+      }
+      let padding = " ".repeat(width.saturating_sub(cell.width()));
+      match column.alignment {
+        Alignment::Left => {
+          s.push_str(cell);
+          s.push_str(&padding);
+        }
+        Alignment::Right => {
+          s.push_str(&padding);
+          s.push_str(cell);
+        }
+      }
+    }
+    s
+  }
+}
+
+impl View for Table {
+  fn draw(&self, window: &mut Window) {
+    let height = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw table -- invalid region.")
+      .height as usize;
+    let widths = self.column_widths();
+
+    window.clear_region(self.region_index);
+    let header = self.format_row(
+      self.columns.iter().map(|column| column.title.as_str()),
+      &widths,
+    );
+    window.draw(
+      &header,
+      Color::Black,
+      self.fg,
+      Coord { row: 0, col: 0 },
+      self.region_index,
+    );
+
+    for (row, cells) in self
+      .rows
+      .iter()
+      .skip(self.offset)
+      .take(height.saturating_sub(1))
+      .enumerate()
+    {
+      let s = self.format_row(cells.iter().map(String::as_str), &widths);
+      window.draw(
+        &s,
+        self.fg,
+        Color::Reset,
+        Coord {
+          row: row as u16 + 1,
+          col: 0,
+        },
+        self.region_index,
+      );
+    }
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
+
+impl KeyEventHandleable for Table {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    let last = self.rows.len().saturating_sub(1);
+    match event.code {
+      KeyCode::Up => self.offset = self.offset.saturating_sub(1),
+      KeyCode::Down => self.offset = cmp::min(self.offset + 1, last),
+      KeyCode::Home => self.offset = 0,
+      KeyCode::End => self.offset = last,
+      _ => (),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyEventKind, KeyEventState, KeyModifiers};
+
+  use super::*;
+
+  #[test]
+  fn it_aligns_columns() {
+    let mut window = Window::new(16, 3);
+    let mut table = Table::new(
+      0,
+      vec![
+        Column {
+          title: "Name".to_owned(),
+          alignment: Alignment::Left,
+        },
+        Column {
+          title: "WPM".to_owned(),
+          alignment: Alignment::Right,
+        },
+      ],
+    );
+    table.set_rows(vec![
+      vec!["a".to_owned(), "120".to_owned()],
+      vec!["bbbbbb".to_owned(), "8".to_owned()],
+    ]);
+
+    table.draw(&mut window);
+
+    assert_eq!(
+      window.snapshot(),
+      vec!["Name    WPM     ", "a       120     ", "bbbbbb    8     ",]
+    );
+
+    table.handle_key_event(KeyEvent {
+      code: KeyCode::Down,
+      modifiers: KeyModifiers::empty(),
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    });
+    table.draw(&mut window);
+
+    assert_eq!(window.snapshot()[1], "bbbbbb    8     ");
+  }
+}
@@ -0,0 +1,66 @@
+use crossterm::style::Color;
+
+use super::view::View;
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+use crate::framework::wrap::wrap_text;
+
+// lines of plain text, each wrapped to the width of the region
+pub struct TextBlock {
+  region_index: usize,
+  lines: Vec<String>,
+  fg: Color,
+}
+
+impl TextBlock {
+  pub fn new(region_index: usize) -> Self {
+    TextBlock {
+      region_index,
+      lines: vec![],
+      fg: Color::White,
+    }
+  }
+
+  pub fn set_lines(&mut self, lines: Vec<String>) {
+    self.lines = lines;
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.fg = fg;
+  }
+}
+
+impl View for TextBlock {
+  fn draw(&self, window: &mut Window) {
+    let width = window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw text -- invalid region.")
+      .width as usize;
+
+    window.clear_region(self.region_index);
+    let rows = self.lines.iter().flat_map(|line| {
+      if line.is_empty() {
+        // keep blank lines, which wrap to nothing
+        vec![String::new()]
+      } else {
+        wrap_text(line, width)
+      }
+    });
+    for (row, s) in rows.enumerate() {
+      window.draw(
+        &s,
+        self.fg,
+        Color::Reset,
+        Coord {
+          row: row as u16,
+          col: 0,
+        },
+        self.region_index,
+      );
+    }
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
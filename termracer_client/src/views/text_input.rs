@@ -0,0 +1,254 @@
+use std::ops::Range;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Color;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use super::view::{KeyEventHandleable, View};
+use crate::framework::coord::Coord;
+use crate::framework::window::Window;
+
+// a single line of editable text
+pub struct TextInput {
+  region_index: usize,
+  graphemes: Vec<String>,
+  // grapheme index the next input is inserted at
+  cursor: usize,
+  fg: Color,
+}
+
+impl TextInput {
+  pub fn new(region_index: usize, value: &str) -> Self {
+    let mut input = TextInput {
+      region_index,
+      graphemes: vec![],
+      cursor: 0,
+      fg: Color::White,
+    };
+    input.set_value(value);
+    input
+  }
+
+  pub fn value(&self) -> String {
+    self.graphemes.concat()
+  }
+
+  // replaces the text, moving the cursor to the end
+  pub fn set_value(&mut self, value: &str) {
+    self.graphemes = value.graphemes(true).map(String::from).collect();
+    self.cursor = self.graphemes.len();
+  }
+
+  pub fn set_color(&mut self, fg: Color) {
+    self.fg = fg;
+  }
+
+  // draws the input at a position within any region, for views that contain
+  // an input without giving it its own region
+  pub fn draw_at(&self, window: &mut Window, region_index: usize, coord: Coord, width: usize) {
+    let (visible, _) = self.visible(width);
+    let mut text: String = self.graphemes[visible].concat();
+    // pad out the rest of the width in case the text got shorter
+    text.push_str(&" ".repeat(width.saturating_sub(text.width())));
+    window.draw(&text, self.fg, Color::Reset, coord, region_index);
+  }
+
+  pub fn reset_cursor_at(
+    &self,
+    window: &mut Window,
+    region_index: usize,
+    coord: Coord,
+    width: usize,
+  ) {
+    let (_, col) = self.visible(width);
+    window.set_cursor(
+      coord
+        + Coord {
+          row: 0,
+          col: col as u16,
+        },
+      region_index,
+    );
+  }
+
+  fn region_width(&self, window: &Window) -> usize {
+    window
+      .region(self.region_index)
+      .expect("ERROR: Failed to draw text input -- invalid region.")
+      .width as usize
+  }
+
+  // the range of graphemes that fit in the width, scrolled to keep the cursor
+  // in view, along with the column of the cursor
+  fn visible(&self, width: usize) -> (Range<usize>, usize) {
+    // leave a column for the cursor at the end of the text
+    let width = width.saturating_sub(1);
+    let mut start = 0;
+    let text_width =
+      |range: Range<usize>| -> usize { self.graphemes[range].iter().map(|g| g.width()).sum() };
+    while text_width(start..self.cursor) > width {
+      start += 1;
+    }
+    let mut end = self.cursor;
+    while end < self.graphemes.len() && text_width(start..end + 1) <= width {
+      end += 1;
+    }
+    (start..end, text_width(start..self.cursor))
+  }
+}
+
+impl View for TextInput {
+  fn draw(&self, window: &mut Window) {
+    let width = self.region_width(window);
+    self.draw_at(window, self.region_index, Coord { row: 0, col: 0 }, width);
+  }
+
+  fn get_region_index(&self) -> usize {
+    self.region_index
+  }
+}
+
+impl KeyEventHandleable for TextInput {
+  fn handle_key_event(&mut self, event: KeyEvent) {
+    let modified = event
+      .modifiers
+      .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    match event.code {
+      KeyCode::Char(c) if !modified => {
+        self.graphemes.insert(self.cursor, c.to_string());
+        self.cursor += 1;
+        // a combining character merges into the grapheme before it
+        let value = self.value();
+        let cursor_offset = self.graphemes.len() - self.cursor;
+        self.graphemes = value.graphemes(true).map(String::from).collect();
+        self.cursor = self.graphemes.len() - cursor_offset;
+      }
+      KeyCode::Backspace if self.cursor > 0 => {
+        self.cursor -= 1;
+        self.graphemes.remove(self.cursor);
+      }
+      KeyCode::Delete if self.cursor < self.graphemes.len() => {
+        self.graphemes.remove(self.cursor);
+      }
+      KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+      KeyCode::Right => self.cursor = (self.cursor + 1).min(self.graphemes.len()),
+      KeyCode::Home => self.cursor = 0,
+      KeyCode::End => self.cursor = self.graphemes.len(),
+      _ => (),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crossterm::event::{KeyEventKind, KeyEventState};
+
+  use super::*;
+
+  fn create_key_event(code: KeyCode) -> KeyEvent {
+    KeyEvent {
+      code,
+      modifiers: KeyModifiers::empty(),
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    }
+  }
+
+  fn type_keys(input: &mut TextInput, codes: &[KeyCode]) {
+    for &code in codes {
+      input.handle_key_event(create_key_event(code));
+    }
+  }
+
+  #[test]
+  fn it_edits_text() {
+    let mut input = TextInput::new(0, "ac");
+
+    type_keys(
+      &mut input,
+      &[
+        KeyCode::Left,
+        KeyCode::Char('b'),
+        KeyCode::End,
+        KeyCode::Backspace,
+        KeyCode::Home,
+        KeyCode::Delete,
+        KeyCode::Char('x'),
+      ],
+    );
+
+    assert_eq!(input.value(), "xb");
+    assert_eq!(input.cursor, 1);
+  }
+
+  #[test]
+  fn it_scrolls_to_cursor() {
+    let mut window = Window::new(4, 1);
+    let mut input = TextInput::new(0, "abcdef");
+
+    input.draw(&mut window);
+    assert_eq!(window.snapshot(), vec!["def "]);
+
+    type_keys(&mut input, &[KeyCode::Home]);
+    input.draw(&mut window);
+    assert_eq!(window.snapshot(), vec!["abc "]);
+  }
+
+  #[test]
+  fn it_edits_at_cursor() {
+    let mut input = TextInput::new(0, "abcd");
+
+    type_keys(
+      &mut input,
+      &[KeyCode::Left, KeyCode::Left, KeyCode::Char('x')],
+    );
+    assert_eq!(input.value(), "abxcd");
+    assert_eq!(input.cursor, 3);
+
+    type_keys(&mut input, &[KeyCode::Backspace, KeyCode::Backspace]);
+    assert_eq!(input.value(), "acd");
+    assert_eq!(input.cursor, 1);
+
+    type_keys(
+      &mut input,
+      &[KeyCode::Delete, KeyCode::Right, KeyCode::Char('y')],
+    );
+    assert_eq!(input.value(), "ady");
+    assert_eq!(input.cursor, 3);
+
+    // nothing to delete past either end
+    type_keys(
+      &mut input,
+      &[KeyCode::Delete, KeyCode::Home, KeyCode::Backspace],
+    );
+    assert_eq!(input.value(), "ady");
+    assert_eq!(input.cursor, 0);
+  }
+
+  #[test]
+  fn it_merges_combining_characters_at_cursor() {
+    let mut input = TextInput::new(0, "eb");
+
+    type_keys(&mut input, &[KeyCode::Left, KeyCode::Char('\u{301}')]);
+    assert_eq!(input.value(), "e\u{301}b");
+    assert_eq!(input.graphemes.len(), 2);
+    assert_eq!(input.cursor, 1);
+
+    type_keys(&mut input, &[KeyCode::Backspace]);
+    assert_eq!(input.value(), "b");
+  }
+
+  #[test]
+  fn it_ignores_modified_characters() {
+    let mut input = TextInput::new(0, "ab");
+
+    input.handle_key_event(KeyEvent {
+      code: KeyCode::Char('c'),
+      modifiers: KeyModifiers::CONTROL,
+      kind: KeyEventKind::Press,
+      state: KeyEventState::empty(),
+    });
+    assert_eq!(input.value(), "ab");
+  }
+}
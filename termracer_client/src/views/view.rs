@@ -1,10 +1,16 @@
 use crossterm::event::KeyEvent;
 
+use crate::framework::frame_scheduler::Refresh;
 use crate::framework::window::Window;
 
 pub trait View {
   fn draw(&self, window: &mut Window);
   fn get_region_index(&self) -> usize;
+
+  // how often the view needs drawing when drawn through a RefreshTimer
+  fn refresh(&self) -> Refresh {
+    Refresh::EveryFrame
+  }
 }
 
 pub trait KeyEventHandleable {
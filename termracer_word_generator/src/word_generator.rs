@@ -1,4 +1,6 @@
 use rand::distributions::{Distribution, Uniform};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 lazy_static! {
     // source: https://www.ef.edu/english-resources/english-vocabulary/top-3000-words/
@@ -8,11 +10,35 @@ lazy_static! {
         .collect();
 }
 
-pub fn generate_words(count: usize) -> Vec<String> {
-  let mut rng = rand::thread_rng();
-  let dist = Uniform::from(0..WORDS.len());
+// the same seed always generates the same words, so a test can be repeated
+pub fn generate_words_seeded(count: usize, seed: u64) -> Vec<String> {
+  generate_words_from_seeded(&WORDS, count, seed)
+}
+
+// picks words at random from a custom list
+pub fn generate_words_from_seeded(words: &[String], count: usize, seed: u64) -> Vec<String> {
+  pick(words, count, &mut StdRng::seed_from_u64(seed))
+}
+
+pub fn random_seed() -> u64 {
+  rand::thread_rng().gen()
+}
+
+fn pick<R: Rng>(words: &[String], count: usize, rng: &mut R) -> Vec<String> {
+  let dist = Uniform::from(0..words.len());
 
   (0..count)
-    .map(|_| WORDS.get(dist.sample(&mut rng)).unwrap().clone())
+    .map(|_| words.get(dist.sample(rng)).unwrap().clone())
     .collect()
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_repeats_seeded_words() {
+    assert_eq!(generate_words_seeded(20, 42), generate_words_seeded(20, 42));
+    assert_ne!(generate_words_seeded(20, 42), generate_words_seeded(20, 43));
+  }
+}